@@ -7,12 +7,21 @@
 
 use std::sync::Arc;
 
-use jsonrpsee::RpcModule;
+use codec::Encode;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+	RpcModule,
+};
+use pallet_simple_governance::{runtime_api::GovernanceApi as GovernanceRuntimeApi, ProposalId};
 use sc_transaction_pool_api::TransactionPool;
-use solochain_template_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use serde::Serialize;
+use solochain_template_runtime::{opaque::Block, AccountId, Balance, Hash, Nonce, RuntimeEvent};
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
+use sp_core::Bytes;
 
 /// Full client dependencies.
 pub struct FullDeps<C, P> {
@@ -33,6 +42,7 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: GovernanceRuntimeApi<Block, RuntimeEvent, Hash>,
 	P: TransactionPool + 'static,
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
@@ -42,7 +52,8 @@ where
 	let FullDeps { client, pool } = deps;
 
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Governance::new(client).into_rpc())?;
 
 	// Extend this RPC with a custom API by using the following syntax.
 	// `YourRpcStruct` should have a reference to a client, which is needed
@@ -58,3 +69,80 @@ where
 
 	Ok(module)
 }
+
+/// The effects of dry-running a call proposal. All of them have been rolled back.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRun {
+	/// The dispatch error, or `None` if the call succeeded.
+	pub error: Option<String>,
+	/// The SCALE-encoded events emitted by the call.
+	pub events: Vec<Bytes>,
+	/// The reference time used by the call.
+	pub ref_time: u64,
+	/// The proof size used by the call.
+	pub proof_size: u64,
+	/// State root before dispatching the call.
+	pub root_before: Hash,
+	/// State root after dispatching the call.
+	pub root_after: Hash,
+	/// Whether the call changed storage.
+	pub storage_changed: bool,
+}
+
+/// Governance RPC methods.
+#[rpc(server)]
+pub trait GovernanceApi {
+	/// Dispatch the call of proposal `proposal_id` with the governance origin at block `at`, or
+	/// the best block, and return its effects without applying them.
+	#[method(name = "governance_dryRun")]
+	fn dry_run(&self, proposal_id: ProposalId, at: Option<Hash>) -> RpcResult<DryRun>;
+}
+
+/// Implements the `GovernanceApi` RPC on top of the `GovernanceApi` runtime API.
+pub struct Governance<C> {
+	client: Arc<C>,
+}
+
+impl<C> Governance<C> {
+	/// Create a new `Governance` RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+/// Error code of a failed runtime API call.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code of a proposal that cannot be dry-run.
+const DRY_RUN_ERROR: i32 = 2;
+
+impl<C> GovernanceApiServer for Governance<C>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: GovernanceRuntimeApi<Block, RuntimeEvent, Hash>,
+{
+	fn dry_run(&self, proposal_id: ProposalId, at: Option<Hash>) -> RpcResult<DryRun> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let effects = self
+			.client
+			.runtime_api()
+			.dry_run(at, proposal_id)
+			.map_err(|e| error(RUNTIME_ERROR, "Unable to dry-run proposal", e))?
+			.map_err(|e| error(DRY_RUN_ERROR, "Proposal cannot be dry-run", e))?;
+
+		Ok(DryRun {
+			error: effects.result.err().map(|e| format!("{e:?}")),
+			events: effects.events.iter().map(|event| event.encode().into()).collect(),
+			ref_time: effects.weight.ref_time(),
+			proof_size: effects.weight.proof_size(),
+			storage_changed: effects.storage.changed(),
+			root_before: effects.storage.root_before,
+			root_after: effects.storage.root_after,
+		})
+	}
+}
+
+fn error(code: i32, message: &str, e: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(code, message, Some(format!("{e:?}")))
+}
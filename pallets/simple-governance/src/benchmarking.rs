@@ -2,11 +2,39 @@
 
 use super::*;
 use crate::Pallet as SimpleGovernance;
+use codec::Encode;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
-use alloc::vec;
+use frame_support::{
+    traits::{Currency, EnsureOrigin},
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use alloc::{boxed::Box, vec};
+use sp_runtime::traits::{Convert, Hash, Saturating, Zero};
 
-#[benchmarks]
+/// Open `count` proposals from distinct accounts so the open-proposal set is as large as the
+/// benchmarked call can encounter.
+fn fill_active_proposals<T: Config<I>, I: 'static>(count: u32) {
+    for i in 0..count {
+        let proposer: T::AccountId = account("proposer", i, 0);
+        assert_ok!(SimpleGovernance::<T, I>::propose(
+            RawOrigin::Signed(proposer).into(),
+            vec![0u8; 100]
+        ));
+    }
+}
+
+/// An `ActionProposeOrigin` and the proposer it yields.
+fn action_proposer<T: Config<I>, I: 'static>(
+) -> Result<(<T as frame_system::Config>::RuntimeOrigin, T::AccountId), BenchmarkError> {
+    let origin =
+        T::ActionProposeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    let proposer = T::ActionProposeOrigin::ensure_origin(origin.clone())
+        .map_err(|_| BenchmarkError::Weightless)?;
+    Ok((origin, proposer))
+}
+
+#[instance_benchmarks]
 mod benchmarks {
     use super::*;
 
@@ -14,15 +42,19 @@ mod benchmarks {
     fn propose() {
         let caller: T::AccountId = whitelisted_caller();
         let description = vec![0u8; T::MaxDescriptionLength::get() as usize];
+
+        // Leave exactly one free slot in the open-proposal set
+        let existing = T::MaxActiveProposals::get().saturating_sub(1);
+        fill_active_proposals::<T, I>(existing);
         
         #[extrinsic_call]
         propose(RawOrigin::Signed(caller.clone()), description.clone());
 
         // Verify the proposal was created
-        assert_eq!(SimpleGovernance::<T>::next_proposal_id(), 1);
-        assert!(SimpleGovernance::<T>::proposals(0).is_some());
+        assert_eq!(SimpleGovernance::<T, I>::next_proposal_id(), existing + 1);
+        assert!(SimpleGovernance::<T, I>::proposals(existing).is_some());
         
-        let proposal = SimpleGovernance::<T>::proposals(0).unwrap();
+        let proposal = SimpleGovernance::<T, I>::proposals(existing).unwrap();
         assert_eq!(proposal.proposer, caller);
         assert_eq!(proposal.description.into_inner(), description);
     }
@@ -34,54 +66,76 @@ mod benchmarks {
         let description = vec![0u8; 100];
         
         // Create a proposal first
-        assert_ok!(SimpleGovernance::<T>::propose(
+        assert_ok!(SimpleGovernance::<T, I>::propose(
             RawOrigin::Signed(proposer).into(),
             description
         ));
+
+        // Vote in the last block of the voting period so that flipping the outcome extends it
+        let end_block = SimpleGovernance::<T, I>::proposals(0).unwrap().end_block;
+        frame_system::Pallet::<T>::set_block_number(end_block);
         
         #[extrinsic_call]
         vote(RawOrigin::Signed(voter.clone()), 0, true);
 
         // Verify the vote was recorded
-        assert_eq!(SimpleGovernance::<T>::votes(0, &voter), Some(true));
+        let record = SimpleGovernance::<T, I>::votes(0, &voter).unwrap();
+        assert!(record.vote);
+        assert!(VotesByAccount::<T, I>::contains_key(&voter, 0));
         
-        let tally = SimpleGovernance::<T>::vote_tallies(0).unwrap();
+        let tally = SimpleGovernance::<T, I>::vote_tallies(0).unwrap();
         assert_eq!(tally.for_votes, 1);
         assert_eq!(tally.against_votes, 0);
     }
 
     #[benchmark]
-    fn close_proposal() {
-        let proposer: T::AccountId = whitelisted_caller();
+    fn close_proposal() -> Result<(), BenchmarkError> {
+        let (origin, _) = action_proposer::<T, I>()?;
         let closer: T::AccountId = account("closer", 0, 0);
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
         let description = vec![0u8; 100];
         
-        // Create a proposal
-        assert_ok!(SimpleGovernance::<T>::propose(
-            RawOrigin::Signed(proposer).into(),
-            description
+        // Create a vested spend, the most expensive proposal to enact
+        let track = T::Tracks::default_track().unwrap();
+        let amount = T::SpendLimit::convert(track);
+        let pot = amount.saturating_mul(2u32.into()).saturating_add(T::Currency::minimum_balance());
+        T::Currency::make_free_balance_be(&T::TreasuryAccount::get(), pot);
+        assert_ok!(SimpleGovernance::<T, I>::propose_spend(
+            origin,
+            track,
+            description,
+            beneficiary.clone(),
+            amount,
+            Some(SpendVesting { per_block: 1u32.into(), starting_block: 0u32.into() }),
         ));
+
+        // Fill the rest of the open-proposal set
+        fill_active_proposals::<T, I>(T::MaxActiveProposals::get().saturating_sub(1));
         
         // Add some votes
         let voter1: T::AccountId = account("voter1", 0, 0);
         let voter2: T::AccountId = account("voter2", 0, 0);
         
-        assert_ok!(SimpleGovernance::<T>::vote(
+        assert_ok!(SimpleGovernance::<T, I>::vote(
             RawOrigin::Signed(voter1).into(),
             0,
             true
         ));
         
-        assert_ok!(SimpleGovernance::<T>::vote(
+        assert_ok!(SimpleGovernance::<T, I>::vote(
             RawOrigin::Signed(voter2).into(),
             0,
             false
         ));
         
+        // Let the whole electorate approve it
+        let electorate = T::EligibleElectorate::get().max(1);
+        VoteTallies::<T, I>::insert(0, VoteTally { for_votes: electorate, against_votes: 0 });
+        
         // Move past voting period by setting the proposal as ended
         // We'll modify the proposal directly for benchmarking purposes
         let current_block = frame_system::Pallet::<T>::block_number();
-        Proposals::<T>::mutate(0, |proposal_opt| {
+        Proposals::<T, I>::mutate(0, |proposal_opt| {
             if let Some(proposal) = proposal_opt {
                 proposal.end_block = current_block.saturating_sub(1u32.into());
             }
@@ -90,10 +144,379 @@ mod benchmarks {
         #[extrinsic_call]
         close_proposal(RawOrigin::Signed(closer), 0);
 
-        // Verify the proposal was closed
-        let proposal = SimpleGovernance::<T>::proposals(0).unwrap();
+        // Verify the proposal was closed and the spend paid out
+        let proposal = SimpleGovernance::<T, I>::proposals(0).unwrap();
         assert!(proposal.is_closed);
+        assert!(proposal.approved);
+        assert_eq!(T::Currency::free_balance(&beneficiary), amount);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn propose_spend() -> Result<(), BenchmarkError> {
+        let (origin, _) = action_proposer::<T, I>()?;
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        let description = vec![0u8; T::MaxDescriptionLength::get() as usize];
+        let track = T::Tracks::default_track().unwrap();
+        let amount = T::SpendLimit::convert(track);
+        let vesting = SpendVesting { per_block: 1u32.into(), starting_block: 0u32.into() };
+
+        let existing = T::MaxActiveProposals::get().saturating_sub(1);
+        fill_active_proposals::<T, I>(existing);
+
+        #[extrinsic_call]
+        propose_spend(
+            origin as <T as frame_system::Config>::RuntimeOrigin,
+            track,
+            description,
+            beneficiary.clone(),
+            amount,
+            Some(vesting.clone()),
+        );
+
+        assert_eq!(
+            SimpleGovernance::<T, I>::proposal_actions(existing),
+            Some(ProposalAction::Spend { beneficiary, amount, vesting: Some(vesting) })
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn fast_track() -> Result<(), BenchmarkError> {
+        let proposer: T::AccountId = whitelisted_caller();
+        assert_ok!(SimpleGovernance::<T, I>::propose(
+            RawOrigin::Signed(proposer).into(),
+            vec![0u8; 100]
+        ));
+        let origin =
+            T::FastTrackOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let voting_period = T::MinFastTrackPeriod::get();
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin, 0, voting_period);
+
+        let proposal = SimpleGovernance::<T, I>::proposals(0).unwrap();
+        assert!(proposal.emergency);
+        assert_eq!(
+            proposal.end_block,
+            frame_system::Pallet::<T>::block_number().saturating_add(voting_period)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn propose_upgrade() -> Result<(), BenchmarkError> {
+        let (origin, _) = action_proposer::<T, I>()?;
+        let description = vec![0u8; T::MaxDescriptionLength::get() as usize];
+        let track = T::Tracks::default_track().unwrap();
+        let code_hash = T::Hashing::hash(&[1u8; 32]);
+
+        let existing = T::MaxActiveProposals::get().saturating_sub(1);
+        fill_active_proposals::<T, I>(existing);
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin, track, description, code_hash, 2);
+
+        assert_eq!(
+            SimpleGovernance::<T, I>::proposal_actions(existing),
+            Some(ProposalAction::AuthorizeUpgrade { code_hash, spec_version: 2 })
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn propose_committee_change() -> Result<(), BenchmarkError> {
+        let (origin, _) = action_proposer::<T, I>()?;
+        let description = vec![0u8; T::MaxDescriptionLength::get() as usize];
+        let track = T::Tracks::default_track().unwrap();
+        let member: T::AccountId = account("member", 0, 0);
+        let change = MembershipChange::Add(member);
+
+        let existing = T::MaxActiveProposals::get().saturating_sub(1);
+        fill_active_proposals::<T, I>(existing);
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin, track, description, change.clone());
+
+        assert_eq!(
+            SimpleGovernance::<T, I>::proposal_actions(existing),
+            Some(ProposalAction::ChangeCommittee(change))
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel() -> Result<(), BenchmarkError> {
+        let proposer: T::AccountId = whitelisted_caller();
+        assert_ok!(SimpleGovernance::<T, I>::propose(
+            RawOrigin::Signed(proposer).into(),
+            vec![0u8; 100]
+        ));
+        let origin =
+            T::CancelOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin, 0);
+
+        let proposal = SimpleGovernance::<T, I>::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert_eq!(proposal.close_reason, Some(CloseReason::Cancelled));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn propose_validator_change() -> Result<(), BenchmarkError> {
+        let (origin, _) = action_proposer::<T, I>()?;
+        let description = vec![0u8; T::MaxDescriptionLength::get() as usize];
+        let track = T::Tracks::default_track().unwrap();
+        let validator: T::AccountId = account("validator", 0, 0);
+        let change = MembershipChange::Add(validator);
+
+        let existing = T::MaxActiveProposals::get().saturating_sub(1);
+        fill_active_proposals::<T, I>(existing);
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin, track, description, change.clone());
+
+        assert_eq!(
+            SimpleGovernance::<T, I>::proposal_actions(existing),
+            Some(ProposalAction::ChangeValidators(change))
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_parameters() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ParametersOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let parameters = GovernanceParameters {
+            max_description_length: MAX_DESCRIPTION_LENGTH,
+            voting_period: T::MaxVotingPeriod::get(),
+            max_proposals_per_block: T::MaxActiveProposals::get(),
+        };
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin, parameters);
+
+        assert_eq!(SimpleGovernance::<T, I>::parameters(), parameters);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn propose_call() -> Result<(), BenchmarkError> {
+        let (origin, _) = action_proposer::<T, I>()?;
+        let description = vec![0u8; T::MaxDescriptionLength::get() as usize];
+        let track = T::Tracks::default_track().unwrap();
+        // A remark just short enough to fit, as the call is encoded into the proposal
+        let call: <T as Config<I>>::ProposalCall =
+            frame_system::Call::<T>::remark { remark: vec![0u8; MAX_CALL_LENGTH as usize - 8] }
+                .into();
+
+        let existing = T::MaxActiveProposals::get().saturating_sub(1);
+        fill_active_proposals::<T, I>(existing);
+
+        #[extrinsic_call]
+        _(origin as <T as frame_system::Config>::RuntimeOrigin, track, description, Box::new(call.clone()));
+
+        assert_eq!(
+            SimpleGovernance::<T, I>::proposal_actions(existing),
+            Some(ProposalAction::Dispatch(call.encode().try_into().unwrap()))
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn clean_up_votes(n: Linear<1, 1_000>) {
+        let proposer: T::AccountId = whitelisted_caller();
+        assert_ok!(SimpleGovernance::<T, I>::propose(
+            RawOrigin::Signed(proposer).into(),
+            vec![0u8; 100]
+        ));
+
+        for i in 0..n {
+            let voter: T::AccountId = account("voter", i, 0);
+            let record = VoteRecord {
+                vote: true,
+                block: Zero::zero(),
+                balance: Zero::zero(),
+                conviction: Conviction::None,
+                reason: None,
+            };
+            Votes::<T, I>::insert(0, &voter, &record);
+            VotesByAccount::<T, I>::insert(&voter, 0, record);
+        }
+        Proposals::<T, I>::mutate(0, |proposal| {
+            if let Some(proposal) = proposal {
+                proposal.is_closed = true;
+            }
+        });
+
+        #[block]
+        {
+            assert_ok!(SimpleGovernance::<T, I>::clean_up_votes(0));
+        }
+
+        let remaining = n.saturating_sub(T::MaxVotesRemovedPerTask::get());
+        assert_eq!(Votes::<T, I>::iter_prefix(0).count() as u32, remaining);
+        assert_eq!(VotesByAccount::<T, I>::iter().count() as u32, remaining);
+    }
+
+    #[benchmark]
+    fn vote_with_reason() {
+        let proposer: T::AccountId = whitelisted_caller();
+        let voter: T::AccountId = account("voter", 0, 0);
+        assert_ok!(SimpleGovernance::<T, I>::propose(
+            RawOrigin::Signed(proposer).into(),
+            vec![0u8; 100]
+        ));
+
+        // Vote in the last block of the voting period so that flipping the outcome extends it
+        let end_block = SimpleGovernance::<T, I>::proposals(0).unwrap().end_block;
+        frame_system::Pallet::<T>::set_block_number(end_block);
+
+        let text = BoundedVec::try_from(vec![0u8; MAX_REASON_LENGTH as usize]).unwrap();
+        let reason = VoteReason::Text(text);
+
+        #[extrinsic_call]
+        vote_with_reason(
+            RawOrigin::Signed(voter.clone()),
+            0,
+            true,
+            Conviction::Locked6x,
+            Some(reason.clone())
+        );
+
+        let record = SimpleGovernance::<T, I>::votes(0, &voter).unwrap();
+        assert_eq!(record.reason, Some(reason));
+    }
+
+    #[benchmark]
+    fn vote_signed() {
+        let proposer: T::AccountId = whitelisted_caller();
+        assert_ok!(SimpleGovernance::<T, I>::propose(
+            RawOrigin::Signed(proposer).into(),
+            vec![0u8; 100]
+        ));
+
+        // Vote in the last block of the voting period so that flipping the outcome extends it
+        let end_block = SimpleGovernance::<T, I>::proposals(0).unwrap().end_block;
+        frame_system::Pallet::<T>::set_block_number(end_block);
+
+        let voter = T::BenchmarkHelper::voter();
+        let ballot = Ballot {
+            voter: voter.clone(),
+            proposal_id: 0,
+            vote: true,
+            nonce: 0,
+            genesis_hash: frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero()),
+        };
+        let signature =
+            T::BenchmarkHelper::sign(&voter, &SimpleGovernance::<T, I>::ballot_payload(&ballot));
+
+        #[extrinsic_call]
+        vote_signed(RawOrigin::None, ballot, signature);
+
+        assert_eq!(BallotNonces::<T, I>::get(&voter), 1);
+        assert!(SimpleGovernance::<T, I>::votes(0, &voter).unwrap().vote);
+    }
+
+    #[benchmark]
+    fn vote_many(n: Linear<1, { T::MaxBatchSize::get() }>) {
+        let voter: T::AccountId = account("voter", 0, 0);
+        fill_active_proposals::<T, I>(n);
+
+        // Vote in the last block of the voting period so that flipping the outcome extends it
+        let end_block = SimpleGovernance::<T, I>::proposals(0).unwrap().end_block;
+        frame_system::Pallet::<T>::set_block_number(end_block);
+
+        let votes: BoundedVec<_, T::MaxBatchSize> =
+            BoundedVec::truncate_from((0..n).map(|proposal_id| (proposal_id, true)).collect());
+
+        #[extrinsic_call]
+        vote_many(RawOrigin::Signed(voter.clone()), votes);
+
+        assert_eq!(VotesByAccount::<T, I>::iter_prefix(&voter).count() as u32, n);
+    }
+
+    #[benchmark]
+    fn close_many(n: Linear<1, { T::MaxBatchSize::get() }>) -> Result<(), BenchmarkError> {
+        let (origin, proposer) = action_proposer::<T, I>()?;
+        let closer: T::AccountId = account("closer", 0, 0);
+
+        // Create vested spends, the most expensive proposals to enact
+        let track = T::Tracks::default_track().unwrap();
+        let amount = T::SpendLimit::convert(track);
+        let pot = amount
+            .saturating_mul((n + 1).into())
+            .saturating_add(T::Currency::minimum_balance());
+        T::Currency::make_free_balance_be(&T::TreasuryAccount::get(), pot);
+        for i in 0..n {
+            let beneficiary: T::AccountId = account("beneficiary", i, 0);
+            // Lift the proposer's limits, which would otherwise stop the batch short
+            ActiveProposalsOf::<T, I>::remove(&proposer);
+            LastProposalBlock::<T, I>::remove(&proposer);
+            assert_ok!(SimpleGovernance::<T, I>::propose_spend(
+                origin.clone(),
+                track,
+                vec![0u8; 100],
+                beneficiary,
+                amount,
+                Some(SpendVesting { per_block: 1u32.into(), starting_block: 0u32.into() }),
+            ));
+        }
+
+        // Fill the rest of the open-proposal set
+        fill_active_proposals::<T, I>(T::MaxActiveProposals::get().saturating_sub(n));
+
+        // Let the whole electorate approve the spends and end their voting periods
+        let electorate = T::EligibleElectorate::get().max(1);
+        let current_block = frame_system::Pallet::<T>::block_number();
+        for proposal_id in 0..n {
+            VoteTallies::<T, I>::insert(
+                proposal_id,
+                VoteTally { for_votes: electorate, against_votes: 0 },
+            );
+            Proposals::<T, I>::mutate(proposal_id, |proposal| {
+                if let Some(proposal) = proposal {
+                    proposal.end_block = current_block.saturating_sub(1u32.into());
+                }
+            });
+        }
+
+        let proposal_ids: BoundedVec<_, T::MaxBatchSize> =
+            BoundedVec::truncate_from((0..n).collect());
+
+        #[extrinsic_call]
+        close_many(RawOrigin::Signed(closer), proposal_ids);
+
+        for i in 0..n {
+            assert!(SimpleGovernance::<T, I>::proposals(i).unwrap().approved);
+            let beneficiary: T::AccountId = account("beneficiary", i, 0);
+            assert_eq!(T::Currency::free_balance(&beneficiary), amount);
+        }
+        Ok(())
     }
 
     impl_benchmark_test_suite!(SimpleGovernance, crate::mock::new_test_ext(), crate::mock::Test);
+
+    #[cfg(test)]
+    mod instance_tests {
+        use super::*;
+        use crate::mock::{new_test_ext, Test};
+        use frame_benchmarking::Benchmarking;
+        use frame_support::instances::Instance1;
+
+        /// Runs every benchmark against the mock's second instance, as the suite above does
+        /// for the default one.
+        #[test]
+        fn benchmarks_work_for_second_instance() {
+            for benchmark in <SimpleGovernance<Test, Instance1> as Benchmarking>::benchmarks(false) {
+                new_test_ext().execute_with(|| {
+                    assert_ok!(SimpleGovernance::<Test, Instance1>::test_bench_by_name(
+                        &benchmark.name
+                    ));
+                });
+            }
+        }
+    }
 }
\ No newline at end of file
@@ -11,10 +11,40 @@
 //! - Each proposal has a voting period defined by block numbers
 //! - Network members can vote 'for' or 'against' each proposal
 //! - Each account may vote once per proposal
+//! - The number of open proposals is capped per account and globally, with an optional
+//!   cooldown between proposals from the same account
 //! - Results (for/against counts) are stored on-chain permanently
 //! - Proposals automatically close when their end block is reached
+//! - Optionally, proposals close as soon as their outcome is decided by the eligible electorate
+//! - A vote that flips the outcome shortly before the end block extends the voting period
+//! - Each proposal is submitted on a track whose approval and support curves fall over the
+//!   voting period, so an uncontroversial proposal can pass before its end block
 //! - Anyone can manually close a proposal once the voting period has ended
+//! - Closing proposals and removing the individual votes of closed proposals are also exposed
+//!   as tasks that any node or off-chain worker can submit
+//! - An off-chain worker submits closures of expired proposals and can publish the results of
+//!   closed proposals to an HTTP endpoint
+//! - Spend proposals pay a beneficiary from the treasury pot once approved, optionally vested,
+//!   up to a per-track limit
+//! - In emergencies, a fast-track origin can shorten a proposal's voting period and an instant
+//!   origin can approve and enact it right away
+//! - Upgrade proposals authorize a runtime code hash that anyone can then apply
+//! - Once governance has enacted a proposal, a proposal can remove the chain's sudo key
+//! - Proposals change the members of a committee, whose approvals can serve as the origins
+//!   proposing, cancelling and fast-tracking proposals
+//! - Proposals add and remove validators, taking effect at the next session, including
+//!   validators reported for offences such as GRANDPA equivocations
+//! - The description length, voting period and auto-close batch size are stored on-chain and
+//!   can be changed by a governance origin, within bounds fixed by the runtime
+//! - Other pallets can react to proposals being created, voted on and closed through
+//!   `OnProposalStatusChange` hooks, whose weight is accounted for
+//! - Call proposals dispatch an arbitrary runtime call once approved, with a
+//!   `GovernanceOrigin::Approved` origin that other pallets can require through `EnsureApproved`
+//!   or `EnsureTrack`
 //! - Events are emitted for proposing, voting, and closing proposals
+//! - The pallet is instanceable, so a runtime can host several independent governance bodies,
+//!   e.g. a community referendum body and a technical body, each with its own storage,
+//!   constants and events
 //!
 //! ## Usage
 //!
@@ -58,46 +88,453 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+pub mod tracks;
+pub use tracks::*;
+
+pub mod migrations;
+
+pub mod offchain;
+
+pub mod runtime_api;
+
+use core::marker::PhantomData;
+use frame_support::{traits::EnsureOrigin, weights::Weight};
+
+/// Changes the members of a set of accounts, e.g. a committee through `pallet_membership` or
+/// the validator set.
+pub trait ManageMembers<AccountId> {
+    /// Add `who` to the set.
+    fn add_member(who: &AccountId) -> sp_runtime::DispatchResult;
+
+    /// Remove `who` from the set.
+    fn remove_member(who: &AccountId) -> sp_runtime::DispatchResult;
+}
+
+/// No set, for chains without one. Membership changes fail.
+impl<AccountId> ManageMembers<AccountId> for () {
+    fn add_member(_who: &AccountId) -> sp_runtime::DispatchResult {
+        Err(sp_runtime::DispatchError::Unavailable)
+    }
+
+    fn remove_member(_who: &AccountId) -> sp_runtime::DispatchResult {
+        Err(sp_runtime::DispatchError::Unavailable)
+    }
+}
+
+/// Access to a chain's sudo key, e.g. `pallet_sudo`'s.
+pub trait SudoKey {
+    /// Whether a sudo key is set.
+    fn exists() -> bool;
+
+    /// Remove the sudo key.
+    fn remove() -> sp_runtime::DispatchResult;
+}
+
+/// No sudo key, for chains without sudo.
+impl SudoKey for () {
+    fn exists() -> bool {
+        false
+    }
+
+    fn remove() -> sp_runtime::DispatchResult {
+        Ok(())
+    }
+}
+
+/// Lets other pallets react to proposals being created, voted on and closed. Handlers can be
+/// combined in tuples.
+///
+/// Each method returns the weight it used, which must not exceed [`Self::max_weight`]. The
+/// pallet charges `max_weight` upfront for every call it may make and adds the returned weight
+/// to its hooks' weight.
+pub trait OnProposalStatusChange<AccountId> {
+    /// Proposal `proposal_id` was created by `proposer`.
+    fn on_created(_proposal_id: ProposalId, _proposer: &AccountId) -> Weight {
+        Weight::zero()
+    }
+
+    /// `voter` voted for (`aye`) or against proposal `proposal_id`.
+    fn on_voted(_proposal_id: ProposalId, _voter: &AccountId, _aye: bool) -> Weight {
+        Weight::zero()
+    }
+
+    /// Proposal `proposal_id` was closed for `reason` and passed if `approved`. Called after
+    /// the action of an approved proposal was carried out.
+    fn on_closed(_proposal_id: ProposalId, _approved: bool, _reason: CloseReason) -> Weight {
+        Weight::zero()
+    }
+
+    /// The most weight a single call of any of the methods above may use.
+    fn max_weight() -> Weight {
+        Weight::zero()
+    }
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnProposalStatusChange<AccountId> for Tuple {
+    fn on_created(proposal_id: ProposalId, proposer: &AccountId) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #(
+            weight = weight.saturating_add(Tuple::on_created(proposal_id, proposer));
+        )* );
+        weight
+    }
+
+    fn on_voted(proposal_id: ProposalId, voter: &AccountId, aye: bool) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #(
+            weight = weight.saturating_add(Tuple::on_voted(proposal_id, voter, aye));
+        )* );
+        weight
+    }
+
+    fn on_closed(proposal_id: ProposalId, approved: bool, reason: CloseReason) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #(
+            weight = weight.saturating_add(Tuple::on_closed(proposal_id, approved, reason));
+        )* );
+        weight
+    }
+
+    fn max_weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight = weight.saturating_add(Tuple::max_weight()); )* );
+        weight
+    }
+}
+
+/// Signs ballots for `vote_signed` in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Signature> {
+    /// An account whose ballots `sign` can sign.
+    fn voter() -> AccountId;
+
+    /// Sign `message` as `who`.
+    fn sign(who: &AccountId, message: &[u8]) -> Signature;
+}
+
+/// Ensures the origin is a proposal approved by governance instance `I`, on any track. Yields
+/// the proposal's ID and track.
+pub struct EnsureApproved<I = ()>(PhantomData<I>);
+
+impl<O, I: 'static> EnsureOrigin<O> for EnsureApproved<I>
+where
+    O: Into<Result<GovernanceOrigin<I>, O>> + From<GovernanceOrigin<I>>,
+{
+    type Success = (ProposalId, TrackId);
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().and_then(|origin| match origin {
+            GovernanceOrigin::Approved { proposal_id, track } => Ok((proposal_id, track)),
+            r => Err(O::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::from(GovernanceOrigin::Approved { proposal_id: 0, track: 0 }))
+    }
+}
+
+/// Ensures the origin is a proposal approved by governance instance `I` on track `TRACK`, e.g.
+/// to restrict a call to the technical track. Yields the proposal's ID.
+pub struct EnsureTrack<const TRACK: TrackId, I = ()>(PhantomData<I>);
+
+impl<O, const TRACK: TrackId, I: 'static> EnsureOrigin<O> for EnsureTrack<TRACK, I>
+where
+    O: Into<Result<GovernanceOrigin<I>, O>> + From<GovernanceOrigin<I>>,
+{
+    type Success = ProposalId;
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().and_then(|origin| match origin {
+            GovernanceOrigin::Approved { proposal_id, track } if track == TRACK => Ok(proposal_id),
+            r => Err(O::from(r)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::from(GovernanceOrigin::Approved { proposal_id: 0, track: TRACK }))
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::{
+        dispatch::{GetDispatchInfo, PostDispatchInfo},
         pallet_prelude::*,
-        traits::{Get, ConstU32},
+        storage::{with_storage_layer, with_transaction},
+        traits::{
+            ConstU32, Contains, Currency, ExistenceRequirement, Get, PalletInfoAccess,
+            VestingSchedule,
+        },
+    };
+    use frame_system::{
+        offchain::{AppCrypto, CreateBare, CreateSignedTransaction},
+        pallet_prelude::*,
+    };
+    use sp_version::RuntimeVersion;
+    use sp_runtime::{
+        traits::{
+            Convert, Dispatchable, Hash, IdentifyAccount, Saturating, UniqueSaturatedInto, Verify,
+            Zero,
+        },
+        PerThing, Perbill, TransactionOutcome,
     };
-    use frame_system::pallet_prelude::*;
-    use sp_runtime::traits::{Saturating, Zero};
-    use alloc::vec::Vec;
+    use alloc::{boxed::Box, vec::Vec};
+
+    /// The balance type of the currency spends are paid in.
+    pub type BalanceOf<T, I = ()> =
+        <<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// The action of a proposal in a runtime.
+    pub type ProposalActionOf<T, I = ()> = ProposalAction<
+        <T as frame_system::Config>::AccountId,
+        BalanceOf<T, I>,
+        BlockNumberFor<T>,
+        <T as frame_system::Config>::Hash,
+    >;
+
+    /// A signed ballot in a runtime.
+    pub type BallotOf<T> =
+        Ballot<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::Hash>;
+
+    /// The record of a vote in a runtime.
+    pub type VoteRecordOf<T, I = ()> = VoteRecord<
+        BalanceOf<T, I>,
+        BlockNumberFor<T>,
+        <T as frame_system::Config>::Hash,
+    >;
+
+    /// The current version of the layout of the pallet's storage. See [`migrations`].
+    ///
+    /// [`migrations`]: crate::migrations
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
     #[pallet::pallet]
-    pub struct Pallet<T>(_);
+    #[pallet::storage_version(STORAGE_VERSION)]
+    pub struct Pallet<T, I = ()>(_);
 
     /// The pallet's configuration trait.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config<I: 'static = ()>:
+        frame_system::Config + CreateSignedTransaction<Call<Self, I>> + CreateBare<Call<Self, I>>
+    {
         /// The overarching runtime event type.
-        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        type RuntimeEvent: From<Event<Self, I>>
+            + IsType<<Self as frame_system::Config>::RuntimeEvent>
+            + TryInto<Event<Self, I>>;
         
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
 
-        /// Maximum length of a proposal description.
+        /// Maximum length of a proposal description until changed by `set_parameters`. At most
+        /// 256.
         #[pallet::constant]
         type MaxDescriptionLength: Get<u32>;
 
-        /// Default voting period in blocks.
+        /// Voting period in blocks until changed by `set_parameters`.
         #[pallet::constant]
         type DefaultVotingPeriod: Get<BlockNumberFor<Self>>;
 
-        /// Maximum number of proposals that can be auto-closed per block.
+        /// Maximum number of open proposals evaluated for auto-closing in `on_initialize`, until
+        /// changed by `set_parameters`. Fewer are evaluated once closing them would exceed
+        /// `MAX_INITIALIZE_SHARE` of the block, but always at least one. Further proposals are
+        /// evaluated in `on_idle` as weight allows.
         #[pallet::constant]
         type MaxProposalsPerBlock: Get<u32>;
+
+        /// Maximum number of open proposals a single account may have at once.
+        #[pallet::constant]
+        type MaxActiveProposalsPerAccount: Get<u32>;
+
+        /// Maximum number of open proposals across all accounts.
+        #[pallet::constant]
+        type MaxActiveProposals: Get<u32>;
+
+        /// Minimum number of blocks an account must wait between two proposals.
+        /// Zero disables the cooldown.
+        #[pallet::constant]
+        type ProposalCooldown: Get<BlockNumberFor<Self>>;
+
+        /// Number of accounts eligible to vote on a proposal.
+        ///
+        /// Used to decide proposals before their end block. Zero disables early decisions.
+        #[pallet::constant]
+        type EligibleElectorate: Get<u32>;
+
+        /// Share of the eligible electorate that closes a proposal early once either side
+        /// reaches it. `None` disables early decisions entirely.
+        ///
+        /// When enabled, a proposal also closes early as soon as the remaining electorate
+        /// can no longer change its outcome.
+        #[pallet::constant]
+        type EarlyDecisionThreshold: Get<Option<Perbill>>;
+
+        /// Whether an open proposal is approved as soon as it meets its track's curves,
+        /// before its end block. Also requires a non-zero `EligibleElectorate`.
+        #[pallet::constant]
+        type EarlyApproval: Get<bool>;
+
+        /// Number of blocks before a proposal's end block in which a vote that flips the
+        /// outcome extends the voting period. Zero disables extensions.
+        #[pallet::constant]
+        type ConfirmationWindow: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks the voting period is extended by when the outcome flips inside the
+        /// confirmation window.
+        #[pallet::constant]
+        type DeadlineExtension: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of times a single proposal's voting period can be extended.
+        #[pallet::constant]
+        type MaxDeadlineExtensions: Get<u32>;
+
+        /// The tracks proposals can be submitted on and their decision curves.
+        type Tracks: TracksInfo;
+
+        /// Maximum number of individual votes a single `clean_up_votes` task removes.
+        #[pallet::constant]
+        type MaxVotesRemovedPerTask: Get<u32>;
+
+        /// The identity the off-chain worker signs closure transactions with.
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// Priority of unsigned closure transactions submitted by the off-chain worker.
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+
+        /// Number of blocks an unsigned closure transaction stays valid in the pool. The
+        /// off-chain worker does not resubmit a closure within this many blocks.
+        #[pallet::constant]
+        type UnsignedLongevity: Get<TransactionLongevity>;
+
+        /// The currency spends are paid in.
+        type Currency: Currency<Self::AccountId>;
+
+        /// Locks the funds of vested spends until they have vested.
+        type Vesting: VestingSchedule<
+            Self::AccountId,
+            Moment = BlockNumberFor<Self>,
+            Currency = Self::Currency,
+        >;
+
+        /// The treasury pot spends are paid from.
+        #[pallet::constant]
+        type TreasuryAccount: Get<Self::AccountId>;
+
+        /// Maximum amount a single spend proposal on each track may request, in the same way
+        /// the treasury's `SpendOrigin` limits spends. Zero disallows spends on a track.
+        type SpendLimit: Convert<TrackId, BalanceOf<Self, I>>;
+
+        /// Origin that can shorten the voting period of an open proposal in an emergency,
+        /// e.g. a technical committee.
+        type FastTrackOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Origin that can approve and enact an open proposal immediately, e.g. a unanimous
+        /// technical committee.
+        type InstantOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Shortest voting period, counted from the current block, a fast-tracked proposal
+        /// can be given.
+        #[pallet::constant]
+        type MinFastTrackPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The chain's sudo key, removed by approved `RemoveSudoKey` proposals. `()` for
+        /// chains without sudo.
+        type SudoKey: SudoKey;
+
+        /// Origin that can create proposals, yielding the proposer, e.g. any signed account
+        /// or a committee approval on behalf of the committee's account.
+        type ProposeOrigin: EnsureOrigin<
+            <Self as frame_system::Config>::RuntimeOrigin,
+            Success = Self::AccountId,
+        >;
+
+        /// Origin that can create proposals with an action, such as spends, runtime upgrades
+        /// or membership changes, yielding the proposer, e.g. a council majority on behalf of
+        /// the council's account.
+        ///
+        /// Proposals with an action are never approved before their end block.
+        type ActionProposeOrigin: EnsureOrigin<
+            <Self as frame_system::Config>::RuntimeOrigin,
+            Success = Self::AccountId,
+        >;
+
+        /// Origin that can cancel an open proposal, e.g. a committee.
+        type CancelOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// The committee whose members are changed by approved `ChangeCommittee` proposals.
+        /// `()` for chains without a committee.
+        type Committee: ManageMembers<Self::AccountId>;
+
+        /// The validator set changed by approved `ChangeValidators` proposals, e.g. through a
+        /// validator-set pallet that applies changes at the next session. `()` for chains
+        /// without one.
+        type Validators: ManageMembers<Self::AccountId>;
+
+        /// Validators reported for an offence, which `RemoveOffender` proposals can target,
+        /// e.g. recorded by the offences pallet's handler.
+        type Offenders: Contains<Self::AccountId>;
+
+        /// Shortest voting period `set_parameters` can set.
+        #[pallet::constant]
+        type MinVotingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Longest voting period `set_parameters` can set.
+        #[pallet::constant]
+        type MaxVotingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Origin that can change the governance parameters, e.g. a committee.
+        type ParametersOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Handlers notified when proposals are created, voted on and closed. `()` for none.
+        type GovernanceHooks: OnProposalStatusChange<Self::AccountId>;
+
+        /// The runtime origin, which includes the `GovernanceOrigin` approved calls are
+        /// dispatched with.
+        type RuntimeOrigin: From<Origin<Self, I>>;
+
+        /// The calls `propose_call` proposals can dispatch, i.e. the runtime's `RuntimeCall`.
+        type ProposalCall: Parameter
+            + Dispatchable<
+                RuntimeOrigin = <Self as Config<I>>::RuntimeOrigin,
+                PostInfo = PostDispatchInfo,
+            > + GetDispatchInfo
+            + From<frame_system::Call<Self>>;
+
+        /// Maximum weight of a call `propose_call` proposals can dispatch.
+        #[pallet::constant]
+        type MaxCallWeight: Get<Weight>;
+
+        /// Maximum number of fee-free first votes per block. Zero makes all votes pay fees.
+        #[pallet::constant]
+        type MaxFreeVotesPerBlock: Get<u32>;
+
+        /// Maximum number of votes in a `vote_many` and of proposals in a `close_many` call.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Signature of the ballots relayed with `vote_signed`, verified against the voter's
+        /// account.
+        type BallotSignature: Parameter
+            + Verify<Signer: IdentifyAccount<AccountId = Self::AccountId>>;
+
+        /// Signs ballots in benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId, Self::BallotSignature>;
     }
 
+    /// Origin of the calls dispatched by approved proposals.
+    #[pallet::origin]
+    pub type Origin<T, I = ()> = GovernanceOrigin<I>;
+
     /// Represents a single governance proposal.
     #[pallet::storage]
     #[pallet::getter(fn proposals)]
-    pub type Proposals<T: Config> = StorageMap<
+    pub type Proposals<T: Config<I>, I: 'static = ()> = StorageMap<
         _,
         Blake2_128Concat,
         ProposalId,
@@ -105,29 +542,67 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// The last proposal evaluated by the auto-close hooks. The next evaluation resumes with
+    /// the first open proposal after it.
+    #[pallet::storage]
+    #[pallet::getter(fn process_cursor)]
+    pub type ProcessCursor<T, I = ()> = StorageValue<_, ProposalId, OptionQuery>;
+
     /// Tracks votes for each proposal.
-    /// Double map: ProposalId -> AccountId -> Vote (true = for, false = against)
+    /// Double map: ProposalId -> AccountId -> VoteRecord
     #[pallet::storage]
     #[pallet::getter(fn votes)]
-    pub type Votes<T: Config> = StorageDoubleMap<
+    pub type Votes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         ProposalId,
         Blake2_128Concat,
         T::AccountId,
-        bool,
+        VoteRecordOf<T, I>,
+        OptionQuery,
+    >;
+
+    /// Reverse index of `Votes`: the votes of each account, by proposal. Pruned together with
+    /// `Votes`.
+    #[pallet::storage]
+    #[pallet::getter(fn votes_by_account)]
+    pub type VotesByAccount<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        ProposalId,
+        VoteRecordOf<T, I>,
         OptionQuery,
     >;
 
+    /// Number of votes each account has cast for and against proposals. Unlike `Votes`, this
+    /// is never pruned.
+    #[pallet::storage]
+    #[pallet::getter(fn participation_of)]
+    pub type ParticipationOf<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, VoteTally, ValueQuery>;
+
+    /// The block fee-free votes were last cast in and how many were cast in it.
+    #[pallet::storage]
+    pub type FreeVotes<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, (BlockNumberFor<T>, u32), OptionQuery>;
+
+    /// Nonce of the next ballot each account can have relayed with `vote_signed`.
+    #[pallet::storage]
+    #[pallet::getter(fn ballot_nonce)]
+    pub type BallotNonces<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
     /// The next available proposal ID.
     #[pallet::storage]
     #[pallet::getter(fn next_proposal_id)]
-    pub type NextProposalId<T> = StorageValue<_, ProposalId, ValueQuery>;
+    pub type NextProposalId<T, I = ()> = StorageValue<_, ProposalId, ValueQuery>;
 
     /// Vote tallies for each proposal.
     #[pallet::storage]
     #[pallet::getter(fn vote_tallies)]
-    pub type VoteTallies<T> = StorageMap<
+    pub type VoteTallies<T, I = ()> = StorageMap<
         _,
         Blake2_128Concat,
         ProposalId,
@@ -135,16 +610,70 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// IDs of all proposals that have not been closed yet, in creation order.
+    #[pallet::storage]
+    #[pallet::getter(fn active_proposals)]
+    pub type ActiveProposals<T: Config<I>, I: 'static = ()> =
+        StorageValue<_, BoundedVec<ProposalId, T::MaxActiveProposals>, ValueQuery>;
+
+    /// What approved proposals do. Proposals without an action only record a decision.
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_actions)]
+    pub type ProposalActions<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, ProposalId, ProposalActionOf<T, I>, OptionQuery>;
+
+    /// Number of open proposals created by each account.
+    #[pallet::storage]
+    #[pallet::getter(fn active_proposals_of)]
+    pub type ActiveProposalsOf<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// The block in which each account last created a proposal.
+    #[pallet::storage]
+    #[pallet::getter(fn last_proposal_block)]
+    pub type LastProposalBlock<T: Config<I>, I: 'static = ()> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Number of approved call proposals whose call was dispatched successfully with the
+    /// governance origin, proving that governance can act.
+    #[pallet::storage]
+    #[pallet::getter(fn enacted_proposals)]
+    pub type EnactedProposals<T, I = ()> = StorageValue<_, u32, ValueQuery>;
+
+    /// The parameters given by the `MaxDescriptionLength`, `DefaultVotingPeriod` and
+    /// `MaxProposalsPerBlock` constants.
+    #[pallet::type_value]
+    pub fn DefaultParameters<T: Config<I>, I: 'static>(
+    ) -> GovernanceParameters<BlockNumberFor<T>> {
+        GovernanceParameters {
+            max_description_length: T::MaxDescriptionLength::get(),
+            voting_period: T::DefaultVotingPeriod::get(),
+            max_proposals_per_block: T::MaxProposalsPerBlock::get(),
+        }
+    }
+
+    /// The parameters new proposals are created with and the auto-close hooks use. Defaults to
+    /// the ones given by the constants until changed by `set_parameters`.
+    #[pallet::storage]
+    #[pallet::getter(fn parameters)]
+    pub type Parameters<T: Config<I>, I: 'static = ()> = StorageValue<
+        _,
+        GovernanceParameters<BlockNumberFor<T>>,
+        ValueQuery,
+        DefaultParameters<T, I>,
+    >;
+
     /// Events emitted by the governance pallet.
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
-    pub enum Event<T: Config> {
+    pub enum Event<T: Config<I>, I: 'static = ()> {
         /// A new proposal was created.
         ProposalCreated {
             proposal_id: ProposalId,
             proposer: T::AccountId,
             description: BoundedVec<u8, ConstU32<256>>,
             end_block: BlockNumberFor<T>,
+            track: TrackId,
         },
 
         /// A vote was cast on a proposal.
@@ -152,6 +681,10 @@ pub mod pallet {
             proposal_id: ProposalId,
             voter: T::AccountId,
             vote: bool, // true = for, false = against
+            block: BlockNumberFor<T>,
+            balance: BalanceOf<T, I>,
+            conviction: Conviction,
+            reason: Option<VoteReason<T::Hash>>,
         },
 
         /// A proposal was closed.
@@ -159,12 +692,61 @@ pub mod pallet {
             proposal_id: ProposalId,
             for_votes: u32,
             against_votes: u32,
+            reason: CloseReason,
+            approved: bool,
+        },
+
+        /// Individual votes of a closed proposal were removed. `complete` is false if more
+        /// votes remain to be removed.
+        VotesCleanedUp {
+            proposal_id: ProposalId,
+            removed: u32,
+            complete: bool,
+        },
+
+        /// The action of an approved proposal was carried out. `result` is the error if it
+        /// failed, in which case none of its effects were applied.
+        ProposalEnacted {
+            proposal_id: ProposalId,
+            result: DispatchResult,
+        },
+
+        /// A proposal was fast-tracked and now ends at `end_block`.
+        ProposalFastTracked {
+            proposal_id: ProposalId,
+            end_block: BlockNumberFor<T>,
+        },
+
+        /// A proposal's voting period was extended because its outcome flipped inside the
+        /// confirmation window.
+        VotingPeriodExtended {
+            proposal_id: ProposalId,
+            end_block: BlockNumberFor<T>,
+            extensions: u32,
+        },
+
+        /// The governance parameters were changed. They apply to proposals created from now on.
+        ParametersSet {
+            parameters: GovernanceParameters<BlockNumberFor<T>>,
+        },
+
+        /// A batch of votes was processed. `results` holds the outcome of each vote, in order;
+        /// the votes that were cast also emitted `Voted`.
+        VotedMany {
+            voter: T::AccountId,
+            results: BoundedVec<(ProposalId, DispatchResult), T::MaxBatchSize>,
+        },
+
+        /// A batch of closures was processed. `results` holds the outcome of each closure, in
+        /// order; the proposals that were closed also emitted `ProposalClosed`.
+        ClosedMany {
+            results: BoundedVec<(ProposalId, DispatchResult), T::MaxBatchSize>,
         },
     }
 
     /// Errors that can be returned by the governance pallet.
     #[pallet::error]
-    pub enum Error<T> {
+    pub enum Error<T, I = ()> {
         /// The proposal description exceeds the maximum allowed length.
         DescriptionTooLong,
         
@@ -182,66 +764,113 @@ pub mod pallet {
         
         /// The voting period has ended and the proposal can no longer be voted on.
         VotingPeriodEnded,
+
+        /// The maximum number of open proposals has been reached.
+        TooManyActiveProposals,
+
+        /// The account already has the maximum number of open proposals.
+        TooManyActiveProposalsForAccount,
+
+        /// The account created a proposal too recently and must wait for the cooldown to pass.
+        ProposalCooldownActive,
+
+        /// The requested track does not exist.
+        UnknownTrack,
+
+        /// The proposal has neither expired nor met its track's curves yet.
+        ProposalNotClosable,
+
+        /// The proposal is still open.
+        ProposalNotClosed,
+
+        /// The spend is zero or exceeds the spend limit of the proposal's track.
+        SpendExceedsTrackLimit,
+
+        /// The spend's vesting schedule unlocks nothing per block.
+        InvalidVestingSchedule,
+
+        /// The voting period is shorter than `MinFastTrackPeriod`.
+        VotingPeriodTooShort,
+
+        /// Fast-tracking would not shorten the proposal's voting period.
+        VotingPeriodNotShortened,
+
+        /// The proposal does not authorize a runtime upgrade.
+        NotAnUpgradeProposal,
+
+        /// The code's hash differs from the one the proposal authorizes.
+        UpgradeCodeHashMismatch,
+
+        /// The runtime version could not be read from the code.
+        UpgradeVersionUnreadable,
+
+        /// The code's `spec_version` differs from the one the proposal announced.
+        UpgradeSpecVersionMismatch,
+
+        /// The chain has no sudo key to remove.
+        NoSudoKey,
+
+        /// No call proposal has been dispatched successfully yet, so governance is not known to
+        /// be able to act.
+        NoProposalEnacted,
+
+        /// The account has not been reported for an offence.
+        NotAnOffender,
+
+        /// The description length is zero or longer than descriptions can be stored.
+        DescriptionLengthOutOfBounds,
+
+        /// The voting period is outside `MinVotingPeriod` and `MaxVotingPeriod`.
+        VotingPeriodOutOfBounds,
+
+        /// The number of proposals evaluated per block is zero or exceeds `MaxActiveProposals`.
+        ProposalsPerBlockOutOfBounds,
+
+        /// The encoded call is longer than `MAX_CALL_LENGTH`.
+        CallTooLong,
+
+        /// The call's weight exceeds `MaxCallWeight`.
+        CallTooHeavy,
+
+        /// The call of an approved proposal could not be decoded, e.g. after a runtime upgrade.
+        UndecodableCall,
+
+        /// The ballot is not signed by its voter, or was signed for another chain.
+        InvalidBallot,
+
+        /// The ballot's nonce is not the voter's current ballot nonce.
+        BallotNonceMismatch,
+
+        /// The batch already closed a proposal or dispatched a call, the most it reserves
+        /// weight for. Submit the item again in another batch.
+        BatchWeightExhausted,
     }
 
     /// The pallet's callable functions.
     #[pallet::call]
-    impl<T: Config> Pallet<T> {
-        /// Create a new governance proposal.
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Create a new governance proposal on the default track.
         ///
         /// Parameters:
         /// - `origin`: The account creating the proposal
         /// - `description`: A description of the proposal (bounded by MaxDescriptionLength)
         ///
+        /// Fails if the account is still in its proposal cooldown or if either the per-account
+        /// or the global limit on open proposals has been reached.
+        ///
         /// Emits `ProposalCreated` event on success.
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::propose())]
+        #[pallet::weight(T::WeightInfo::propose().saturating_add(T::GovernanceHooks::max_weight()))]
         pub fn propose(
             origin: OriginFor<T>,
             description: Vec<u8>,
-        ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
-            
-            // Validate description length against the configured maximum
-            ensure!(
-                description.len() <= T::MaxDescriptionLength::get() as usize,
-                Error::<T>::DescriptionTooLong
-            );
-
-            // Create bounded description with fixed size for storage
-            let bounded_description: BoundedVec<u8, ConstU32<256>> = 
-                description.try_into().map_err(|_| Error::<T>::DescriptionTooLong)?;
-
-            let proposal_id = Self::next_proposal_id();
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            let end_block = current_block.saturating_add(T::DefaultVotingPeriod::get());
-
-            let proposal = ProposalInfo {
-                proposer: who.clone(),
-                description: bounded_description.clone(),
-                start_block: current_block,
-                end_block,
-                is_closed: false,
-            };
-
-            // Store the proposal
-            Proposals::<T>::insert(&proposal_id, &proposal);
-            
-            // Initialize vote tally
-            VoteTallies::<T>::insert(&proposal_id, VoteTally::default());
-            
-            // Increment proposal ID for next proposal
-            NextProposalId::<T>::mutate(|id| *id = id.saturating_add(1));
+        ) -> DispatchResultWithPostInfo {
+            let who = T::ProposeOrigin::ensure_origin(origin)?;
+            let track = T::Tracks::default_track().ok_or(Error::<T, I>::UnknownTrack)?;
 
-            // Emit event
-            Self::deposit_event(Event::ProposalCreated {
-                proposal_id,
-                proposer: who,
-                description: bounded_description,
-                end_block,
-            });
+            let hooks_weight = Self::do_propose(who, track, description, None)?;
 
-            Ok(())
+            Ok(Some(T::WeightInfo::propose().saturating_add(hooks_weight)).into())
         }
 
         /// Vote on an existing proposal.
@@ -251,86 +880,1134 @@ pub mod pallet {
         /// - `proposal_id`: The ID of the proposal to vote on
         /// - `vote`: The vote (true = for, false = against)
         ///
+        /// If early decisions are enabled and the vote decides the proposal, the proposal is
+        /// closed immediately with `CloseReason::EarlyDecision`. Otherwise, if the vote flips the
+        /// outcome inside the confirmation window, the voting period is extended.
+        ///
+        /// An account's first vote on an open proposal is free of fees, up to
+        /// `MaxFreeVotesPerBlock` per block, if the runtime wraps its fee payment in
+        /// `pallet_skip_feeless_payment::SkipCheckIfFeeless`.
+        ///
         /// Emits `Voted` event on success.
         #[pallet::call_index(1)]
-        #[pallet::weight(T::WeightInfo::vote())]
+        #[pallet::weight(
+            T::WeightInfo::vote()
+                .saturating_add(Pallet::<T, I>::close_weight())
+                .saturating_add(T::GovernanceHooks::max_weight())
+        )]
+        #[pallet::feeless_if(
+            |origin: &OriginFor<T>, proposal_id: &ProposalId, _vote: &bool| -> bool {
+                Pallet::<T, I>::is_free_vote(origin, *proposal_id)
+            }
+        )]
         pub fn vote(
             origin: OriginFor<T>,
             proposal_id: ProposalId,
             vote: bool,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
+            let free = Self::is_free_vote(&origin, proposal_id);
             let who = ensure_signed(origin)?;
 
-            // Check if proposal exists
-            let proposal = Self::proposals(&proposal_id)
-                .ok_or(Error::<T>::ProposalNotFound)?;
-
-            // Check if proposal is closed
-            ensure!(!proposal.is_closed, Error::<T>::ProposalClosed);
-
-            // Check if voting period has ended
-            let current_block = <frame_system::Pallet<T>>::block_number();
-            ensure!(current_block <= proposal.end_block, Error::<T>::VotingPeriodEnded);
+            let base_weight = T::WeightInfo::vote();
+            let post_info =
+                Self::do_vote(who, proposal_id, vote, Conviction::None, None, true, base_weight)?;
 
-            // Check if account has already voted
-            ensure!(
-                !Votes::<T>::contains_key(&proposal_id, &who),
-                Error::<T>::AlreadyVoted
-            );
+            if free {
+                let now = <frame_system::Pallet<T>>::block_number();
+                FreeVotes::<T, I>::put((now, Self::free_votes_in(now).saturating_add(1)));
+            }
 
-            // Store the vote
-            Votes::<T>::insert(&proposal_id, &who, vote);
+            Ok(post_info)
+        }
+
+        /// Manually close a proposal whose voting period has ended.
+        ///
+        /// Parameters:
+        /// - `origin`: The account closing the proposal, or none for closures submitted by the
+        ///   off-chain worker
+        /// - `proposal_id`: The ID of the proposal to close
+        ///
+        /// Emits `ProposalClosed` event on success.
+        #[pallet::call_index(2)]
+        #[pallet::weight(Pallet::<T, I>::close_weight())]
+        pub fn close_proposal(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+        ) -> DispatchResultWithPostInfo {
+            // Unsigned closures have already been checked by `validate_unsigned`
+            if ensure_none(origin.clone()).is_err() {
+                ensure_signed(origin)?;
+            }
+
+            let (weight, _) = Self::do_close_expired(proposal_id, true)?;
+
+            // Refund the call weight reserved for proposals that dispatch nothing
+            Ok(Some(T::WeightInfo::close_proposal().saturating_add(weight)).into())
+        }
+
+        /// Create a new governance proposal on a specific track.
+        ///
+        /// Parameters:
+        /// - `origin`: The account creating the proposal
+        /// - `track`: The track whose approval and support curves decide the proposal
+        /// - `description`: A description of the proposal (bounded by MaxDescriptionLength)
+        ///
+        /// Subject to the same limits as `propose`.
+        ///
+        /// Emits `ProposalCreated` event on success.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::propose().saturating_add(T::GovernanceHooks::max_weight()))]
+        pub fn propose_on_track(
+            origin: OriginFor<T>,
+            track: TrackId,
+            description: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::ProposeOrigin::ensure_origin(origin)?;
+
+            let hooks_weight = Self::do_propose(who, track, description, None)?;
+
+            Ok(Some(T::WeightInfo::propose().saturating_add(hooks_weight)).into())
+        }
+
+        /// Propose paying `amount` from the treasury pot to `beneficiary` on a specific track.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `ActionProposeOrigin`, yielding the proposer
+        /// - `track`: The track whose curves decide the proposal and whose spend limit applies
+        /// - `description`: A description of the proposal (bounded by MaxDescriptionLength)
+        /// - `beneficiary`: The account receiving the funds
+        /// - `amount`: The amount to pay, at most the track's spend limit
+        /// - `vesting`: If set, the funds are locked in the beneficiary's account and vest
+        ///   according to this schedule
+        ///
+        /// The spend is paid out when the proposal is approved. Subject to the same limits as
+        /// `propose`.
+        ///
+        /// Emits `ProposalCreated` event on success.
+        #[pallet::call_index(4)]
+        #[pallet::weight(
+            T::WeightInfo::propose_spend().saturating_add(T::GovernanceHooks::max_weight())
+        )]
+        pub fn propose_spend(
+            origin: OriginFor<T>,
+            track: TrackId,
+            description: Vec<u8>,
+            beneficiary: T::AccountId,
+            amount: BalanceOf<T, I>,
+            vesting: Option<SpendVesting<BalanceOf<T, I>, BlockNumberFor<T>>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::ActionProposeOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !amount.is_zero() && amount <= T::SpendLimit::convert(track),
+                Error::<T, I>::SpendExceedsTrackLimit
+            );
+            if let Some(vesting) = &vesting {
+                ensure!(!vesting.per_block.is_zero(), Error::<T, I>::InvalidVestingSchedule);
+            }
+
+            let action = ProposalAction::Spend { beneficiary, amount, vesting };
+            let hooks_weight = Self::do_propose(who, track, description, Some(action))?;
+
+            Ok(Some(T::WeightInfo::propose_spend().saturating_add(hooks_weight)).into())
+        }
+
+        /// Shorten the voting period of an open proposal in an emergency.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `FastTrackOrigin`
+        /// - `proposal_id`: The ID of the proposal to fast-track
+        /// - `voting_period`: Blocks from now until the proposal ends, at least
+        ///   `MinFastTrackPeriod`
+        ///
+        /// The proposal is marked as an emergency. Its curves are evaluated over the shortened
+        /// period, so they fall faster.
+        ///
+        /// Emits `ProposalFastTracked` event on success.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::fast_track())]
+        pub fn fast_track(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+            voting_period: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            T::FastTrackOrigin::ensure_origin(origin)?;
+            ensure!(
+                voting_period >= T::MinFastTrackPeriod::get(),
+                Error::<T, I>::VotingPeriodTooShort
+            );
+
+            let mut proposal = Self::proposals(&proposal_id)
+                .ok_or(Error::<T, I>::ProposalNotFound)?;
+            ensure!(!proposal.is_closed, Error::<T, I>::ProposalClosed);
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let end_block = current_block.saturating_add(voting_period);
+            ensure!(end_block < proposal.end_block, Error::<T, I>::VotingPeriodNotShortened);
+
+            proposal.end_block = end_block;
+            proposal.emergency = true;
+            Proposals::<T, I>::insert(&proposal_id, &proposal);
+
+            Self::deposit_event(Event::ProposalFastTracked { proposal_id, end_block });
+
+            Ok(())
+        }
+
+        /// Approve and enact an open proposal immediately, regardless of its votes.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `InstantOrigin`
+        /// - `proposal_id`: The ID of the proposal to enact
+        ///
+        /// The proposal is marked as an emergency and closed with `CloseReason::Instant`.
+        ///
+        /// Emits `ProposalClosed` event on success.
+        #[pallet::call_index(6)]
+        #[pallet::weight(Pallet::<T, I>::close_weight())]
+        pub fn enact_instantly(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+        ) -> DispatchResultWithPostInfo {
+            T::InstantOrigin::ensure_origin(origin)?;
+
+            let mut proposal = Self::proposals(&proposal_id)
+                .ok_or(Error::<T, I>::ProposalNotFound)?;
+            ensure!(!proposal.is_closed, Error::<T, I>::ProposalClosed);
+
+            proposal.emergency = true;
+            let weight =
+                Self::do_close_proposal(proposal_id, proposal, CloseReason::Instant, true);
+
+            Ok(Some(T::WeightInfo::close_proposal().saturating_add(weight)).into())
+        }
+
+        /// Propose upgrading the runtime to the code with hash `code_hash`.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `ActionProposeOrigin`, yielding the proposer
+        /// - `track`: The track whose curves decide the proposal
+        /// - `description`: A description of the proposal (bounded by MaxDescriptionLength)
+        /// - `code_hash`: The hash of the new runtime's WASM blob
+        /// - `spec_version`: The `spec_version` of the new runtime, for voters to verify
+        ///
+        /// Once approved, the code hash is authorized through `frame_system::authorize_upgrade`
+        /// and anyone can apply the blob with `frame_system::apply_authorized_upgrade`. Subject
+        /// to the same limits as `propose`.
+        ///
+        /// Emits `ProposalCreated` event on success.
+        #[pallet::call_index(7)]
+        #[pallet::weight(
+            T::WeightInfo::propose_upgrade().saturating_add(T::GovernanceHooks::max_weight())
+        )]
+        pub fn propose_upgrade(
+            origin: OriginFor<T>,
+            track: TrackId,
+            description: Vec<u8>,
+            code_hash: T::Hash,
+            spec_version: u32,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::ActionProposeOrigin::ensure_origin(origin)?;
+
+            let action = ProposalAction::AuthorizeUpgrade { code_hash, spec_version };
+            let hooks_weight = Self::do_propose(who, track, description, Some(action))?;
+
+            Ok(Some(T::WeightInfo::propose_upgrade().saturating_add(hooks_weight)).into())
+        }
+
+        /// Propose removing the chain's sudo key, leaving governance in control.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `ActionProposeOrigin`, yielding the proposer
+        /// - `track`: The track whose curves decide the proposal
+        /// - `description`: A description of the proposal (bounded by MaxDescriptionLength)
+        ///
+        /// Only possible once an earlier proposal has been enacted successfully, which is
+        /// checked again on enactment, so the chain is not left without a working root.
+        /// Subject to the same limits as `propose`.
+        ///
+        /// Emits `ProposalCreated` event on success.
+        #[pallet::call_index(8)]
+        // Like an upgrade proposal, plus reading the sudo key and the enacted proposal count
+        #[pallet::weight(
+            T::WeightInfo::propose_upgrade()
+                .saturating_add(T::DbWeight::get().reads(2))
+                .saturating_add(T::GovernanceHooks::max_weight())
+        )]
+        pub fn propose_remove_sudo_key(
+            origin: OriginFor<T>,
+            track: TrackId,
+            description: Vec<u8>,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::ActionProposeOrigin::ensure_origin(origin)?;
+
+            ensure!(T::SudoKey::exists(), Error::<T, I>::NoSudoKey);
+            ensure!(EnactedProposals::<T, I>::get() > 0, Error::<T, I>::NoProposalEnacted);
+
+            let action = ProposalAction::RemoveSudoKey;
+            let hooks_weight = Self::do_propose(who, track, description, Some(action))?;
+
+            let weight = T::WeightInfo::propose_upgrade()
+                .saturating_add(T::DbWeight::get().reads(2))
+                .saturating_add(hooks_weight);
+            Ok(Some(weight).into())
+        }
+
+        /// Propose adding a member to or removing one from the committee.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `ActionProposeOrigin`, yielding the proposer
+        /// - `track`: The track whose curves decide the proposal
+        /// - `description`: A description of the proposal (bounded by MaxDescriptionLength)
+        /// - `change`: The member to add or remove
+        ///
+        /// The change is made through `Committee` when the proposal is approved. Subject to the
+        /// same limits as `propose`.
+        ///
+        /// Emits `ProposalCreated` event on success.
+        #[pallet::call_index(9)]
+        #[pallet::weight(
+            T::WeightInfo::propose_committee_change()
+                .saturating_add(T::GovernanceHooks::max_weight())
+        )]
+        pub fn propose_committee_change(
+            origin: OriginFor<T>,
+            track: TrackId,
+            description: Vec<u8>,
+            change: MembershipChange<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::ActionProposeOrigin::ensure_origin(origin)?;
+
+            let action = ProposalAction::ChangeCommittee(change);
+            let hooks_weight = Self::do_propose(who, track, description, Some(action))?;
+
+            Ok(Some(T::WeightInfo::propose_committee_change().saturating_add(hooks_weight)).into())
+        }
+
+        /// Cancel an open proposal, regardless of its votes.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `CancelOrigin`
+        /// - `proposal_id`: The ID of the proposal to cancel
+        ///
+        /// The proposal is closed as rejected with `CloseReason::Cancelled`, so its action is
+        /// never carried out.
+        ///
+        /// Emits `ProposalClosed` event on success.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::cancel().saturating_add(T::GovernanceHooks::max_weight()))]
+        pub fn cancel(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+        ) -> DispatchResultWithPostInfo {
+            T::CancelOrigin::ensure_origin(origin)?;
+
+            let proposal = Self::proposals(&proposal_id)
+                .ok_or(Error::<T, I>::ProposalNotFound)?;
+            ensure!(!proposal.is_closed, Error::<T, I>::ProposalClosed);
+
+            let hooks_weight =
+                Self::do_close_proposal(proposal_id, proposal, CloseReason::Cancelled, false);
+
+            Ok(Some(T::WeightInfo::cancel().saturating_add(hooks_weight)).into())
+        }
+
+        /// Propose adding a validator to or removing one from the validator set.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `ActionProposeOrigin`, yielding the proposer
+        /// - `track`: The track whose curves decide the proposal
+        /// - `description`: A description of the proposal (bounded by MaxDescriptionLength)
+        /// - `change`: The validator to add or remove
+        ///
+        /// The change is made through `Validators` when the proposal is approved and takes
+        /// effect at the next session. It fails on enactment if it would break the validator
+        /// set's limits, e.g. its minimum size. Subject to the same limits as `propose`.
+        ///
+        /// Emits `ProposalCreated` event on success.
+        #[pallet::call_index(11)]
+        #[pallet::weight(
+            T::WeightInfo::propose_validator_change()
+                .saturating_add(T::GovernanceHooks::max_weight())
+        )]
+        pub fn propose_validator_change(
+            origin: OriginFor<T>,
+            track: TrackId,
+            description: Vec<u8>,
+            change: MembershipChange<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::ActionProposeOrigin::ensure_origin(origin)?;
+
+            let action = ProposalAction::ChangeValidators(change);
+            let hooks_weight = Self::do_propose(who, track, description, Some(action))?;
+
+            Ok(Some(T::WeightInfo::propose_validator_change().saturating_add(hooks_weight)).into())
+        }
+
+        /// Propose removing a validator that was reported for an offence.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `ActionProposeOrigin`, yielding the proposer
+        /// - `track`: The track whose curves decide the proposal
+        /// - `description`: A description of the proposal (bounded by MaxDescriptionLength)
+        /// - `offender`: The reported validator
+        ///
+        /// Voters decide whether the offence warrants removal. If the proposal is approved, the
+        /// validator is removed through `Validators` from the next session on. Subject to the
+        /// same limits as `propose`.
+        ///
+        /// Emits `ProposalCreated` event on success.
+        #[pallet::call_index(12)]
+        // Like a validator change proposal, plus reading the offence record
+        #[pallet::weight(
+            T::WeightInfo::propose_validator_change()
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(T::GovernanceHooks::max_weight())
+        )]
+        pub fn propose_offender_removal(
+            origin: OriginFor<T>,
+            track: TrackId,
+            description: Vec<u8>,
+            offender: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::ActionProposeOrigin::ensure_origin(origin)?;
+
+            ensure!(T::Offenders::contains(&offender), Error::<T, I>::NotAnOffender);
+
+            let action = ProposalAction::RemoveOffender(offender);
+            let hooks_weight = Self::do_propose(who, track, description, Some(action))?;
+
+            let weight = T::WeightInfo::propose_validator_change()
+                .saturating_add(T::DbWeight::get().reads(1))
+                .saturating_add(hooks_weight);
+            Ok(Some(weight).into())
+        }
+
+        /// Change the governance parameters.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `ParametersOrigin`
+        /// - `parameters`: The new parameters
+        ///
+        /// The description length must be between 1 and 256, the voting period between
+        /// `MinVotingPeriod` and `MaxVotingPeriod`, and the number of proposals evaluated per
+        /// block between 1 and `MaxActiveProposals`. Proposals created before the change keep
+        /// their voting period.
+        ///
+        /// Emits `ParametersSet` event on success.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::set_parameters())]
+        pub fn set_parameters(
+            origin: OriginFor<T>,
+            parameters: GovernanceParameters<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            T::ParametersOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                (1..=MAX_DESCRIPTION_LENGTH).contains(&parameters.max_description_length),
+                Error::<T, I>::DescriptionLengthOutOfBounds
+            );
+            ensure!(
+                parameters.voting_period >= T::MinVotingPeriod::get() &&
+                    parameters.voting_period <= T::MaxVotingPeriod::get(),
+                Error::<T, I>::VotingPeriodOutOfBounds
+            );
+            ensure!(
+                (1..=T::MaxActiveProposals::get()).contains(&parameters.max_proposals_per_block),
+                Error::<T, I>::ProposalsPerBlockOutOfBounds
+            );
+
+            Parameters::<T, I>::put(parameters);
+
+            Self::deposit_event(Event::ParametersSet { parameters });
+
+            Ok(())
+        }
+
+        /// Propose dispatching `call`.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `ActionProposeOrigin`, yielding the proposer
+        /// - `track`: The track whose curves decide the proposal
+        /// - `description`: A description of the proposal (bounded by MaxDescriptionLength)
+        /// - `call`: The call to dispatch once the proposal is approved
+        ///
+        /// The call is dispatched with `GovernanceOrigin::Approved`, carrying the proposal's ID
+        /// and track, so it only succeeds if the called pallet accepts that origin, e.g. through
+        /// `EnsureTrack`. Its encoding may be at most `MAX_CALL_LENGTH` bytes long and its weight
+        /// at most `MaxCallWeight`. Subject to the same limits as `propose`.
+        ///
+        /// Emits `ProposalCreated` event on success.
+        #[pallet::call_index(14)]
+        #[pallet::weight(
+            T::WeightInfo::propose_call().saturating_add(T::GovernanceHooks::max_weight())
+        )]
+        pub fn propose_call(
+            origin: OriginFor<T>,
+            track: TrackId,
+            description: Vec<u8>,
+            call: Box<<T as Config<I>>::ProposalCall>,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::ActionProposeOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                call.get_dispatch_info().call_weight.all_lte(T::MaxCallWeight::get()),
+                Error::<T, I>::CallTooHeavy
+            );
+            let call = BoundedVec::try_from(call.encode())
+                .map_err(|_| Error::<T, I>::CallTooLong)?;
+
+            let action = ProposalAction::Dispatch(call);
+            let hooks_weight = Self::do_propose(who, track, description, Some(action))?;
+
+            Ok(Some(T::WeightInfo::propose_call().saturating_add(hooks_weight)).into())
+        }
+
+        /// Vote on an existing proposal like `vote`, declaring a conviction and optionally the
+        /// reason for the vote, as text or as the hash of a text published elsewhere.
+        ///
+        /// The conviction is recorded with the vote but neither locks funds nor changes its
+        /// weight in the tally.
+        ///
+        /// Emits `Voted` event on success.
+        #[pallet::call_index(15)]
+        #[pallet::weight(
+            T::WeightInfo::vote_with_reason()
+                .saturating_add(Pallet::<T, I>::close_weight())
+                .saturating_add(T::GovernanceHooks::max_weight())
+        )]
+        pub fn vote_with_reason(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+            vote: bool,
+            conviction: Conviction,
+            reason: Option<VoteReason<T::Hash>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let base_weight = T::WeightInfo::vote_with_reason();
+            Self::do_vote(who, proposal_id, vote, conviction, reason, true, base_weight)
+        }
+
+        /// Cast a vote signed off-chain by the voter, relayed by anyone as an unsigned
+        /// transaction, so voters need no funds for fees.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be none
+        /// - `ballot`: The voter, proposal, vote, the voter's current ballot nonce and the
+        ///   chain's genesis hash
+        /// - `signature`: The voter's signature of `ballot_payload(ballot)`
+        ///
+        /// Each ballot nonce can only be used once, so a ballot cannot be replayed.
+        ///
+        /// Emits `Voted` event on success.
+        #[pallet::call_index(16)]
+        #[pallet::weight(
+            T::WeightInfo::vote_signed()
+                .saturating_add(Pallet::<T, I>::close_weight())
+                .saturating_add(T::GovernanceHooks::max_weight())
+        )]
+        pub fn vote_signed(
+            origin: OriginFor<T>,
+            ballot: BallotOf<T>,
+            signature: T::BallotSignature,
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+
+            ensure!(Self::is_valid_ballot(&ballot, &signature), Error::<T, I>::InvalidBallot);
+            ensure!(
+                ballot.nonce == BallotNonces::<T, I>::get(&ballot.voter),
+                Error::<T, I>::BallotNonceMismatch
+            );
+            BallotNonces::<T, I>::insert(&ballot.voter, ballot.nonce.saturating_add(1));
+
+            let Ballot { voter, proposal_id, vote, .. } = ballot;
+            let base_weight = T::WeightInfo::vote_signed();
+            Self::do_vote(voter, proposal_id, vote, Conviction::None, None, true, base_weight)
+        }
+
+        /// Vote on several proposals in one transaction.
+        ///
+        /// Parameters:
+        /// - `origin`: The account voting
+        /// - `votes`: The proposals to vote on and the vote for each (true = for, false = against)
+        ///
+        /// Each vote is cast as with `vote`. A vote that cannot be cast, e.g. because the
+        /// proposal is closed or the account already voted on it, is skipped instead of failing
+        /// the batch, and its weight is refunded.
+        ///
+        /// The batch reserves weight for closing a single proposal. Only the first vote that
+        /// decides its proposal closes it; proposals decided by later votes stay open until the
+        /// auto-close hooks close them.
+        ///
+        /// Emits `VotedMany` with the result of each vote, after a `Voted` event for each vote
+        /// that was cast.
+        #[pallet::call_index(17)]
+        #[pallet::weight(
+            T::WeightInfo::vote_many(votes.len() as u32)
+                .saturating_add(T::GovernanceHooks::max_weight().saturating_mul(votes.len() as u64))
+                .saturating_add(Pallet::<T, I>::close_weight())
+        )]
+        pub fn vote_many(
+            origin: OriginFor<T>,
+            votes: BoundedVec<(ProposalId, bool), T::MaxBatchSize>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            // The weight of closing a proposal and running hooks is only added for votes that
+            // needed it
+            let mut extra_weight = Weight::zero();
+            let mut may_close = true;
+            let mut cast = 0u32;
+            let mut results = Vec::with_capacity(votes.len());
+            for (proposal_id, vote) in votes {
+                let result = Self::do_vote(
+                    who.clone(),
+                    proposal_id,
+                    vote,
+                    Conviction::None,
+                    None,
+                    may_close,
+                    Weight::zero(),
+                );
+                let result = match result {
+                    Ok(post_info) => {
+                        cast.saturating_inc();
+                        if may_close {
+                            may_close = !Self::proposals(&proposal_id).is_some_and(|p| p.is_closed);
+                        }
+                        extra_weight.saturating_accrue(post_info.actual_weight.unwrap_or_default());
+                        Ok(())
+                    },
+                    Err(err) => {
+                        // Skipped votes only read the proposal and the existing vote
+                        extra_weight.saturating_accrue(T::DbWeight::get().reads(2));
+                        Err(err.error)
+                    },
+                };
+                results.push((proposal_id, result));
+            }
+
+            Self::deposit_event(Event::VotedMany {
+                voter: who,
+                results: BoundedVec::truncate_from(results),
+            });
+
+            Ok(Some(T::WeightInfo::vote_many(cast).saturating_add(extra_weight)).into())
+        }
+
+        /// Close several proposals whose voting periods have ended in one transaction.
+        ///
+        /// Parameters:
+        /// - `origin`: The account closing the proposals
+        /// - `proposal_ids`: The IDs of the proposals to close
+        ///
+        /// Each proposal is closed as with `close_proposal`. A proposal that cannot be closed,
+        /// e.g. because it is still open for voting or already closed, is skipped instead of
+        /// failing the batch, and its weight is refunded.
+        ///
+        /// The batch reserves weight for dispatching a single call. Once an approved proposal
+        /// dispatched its call, approved proposals with calls are skipped with
+        /// `BatchWeightExhausted`.
+        ///
+        /// Emits `ClosedMany` with the result of each closure, after a `ProposalClosed` event for
+        /// each proposal that was closed.
+        #[pallet::call_index(18)]
+        #[pallet::weight(
+            T::WeightInfo::close_many(proposal_ids.len() as u32)
+                .saturating_add(
+                    T::GovernanceHooks::max_weight().saturating_mul(proposal_ids.len() as u64)
+                )
+                .saturating_add(T::MaxCallWeight::get())
+        )]
+        pub fn close_many(
+            origin: OriginFor<T>,
+            proposal_ids: BoundedVec<ProposalId, T::MaxBatchSize>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            // The weight of dispatched calls and hooks is only added for the proposals closed
+            let mut extra_weight = Weight::zero();
+            let mut may_dispatch = true;
+            let mut closed = 0u32;
+            let mut results = Vec::with_capacity(proposal_ids.len());
+            for proposal_id in proposal_ids {
+                let result = match Self::do_close_expired(proposal_id, may_dispatch) {
+                    Ok((weight, dispatched)) => {
+                        closed.saturating_inc();
+                        may_dispatch &= !dispatched;
+                        extra_weight.saturating_accrue(weight);
+                        Ok(())
+                    },
+                    Err(err) => {
+                        // Skipped closures read at most the proposal, its tally and its action
+                        extra_weight.saturating_accrue(T::DbWeight::get().reads(3));
+                        Err(err)
+                    },
+                };
+                results.push((proposal_id, result));
+            }
+
+            Self::deposit_event(Event::ClosedMany { results: BoundedVec::truncate_from(results) });
+
+            Ok(Some(T::WeightInfo::close_many(closed).saturating_add(extra_weight)).into())
+        }
+    }
+
+    /// Hooks that close expired and decided proposals.
+    ///
+    /// `on_initialize` evaluates up to `max_proposals_per_block` open proposals in every block,
+    /// but stops once evaluating or closing the next one would exceed `MAX_INITIALIZE_SHARE` of
+    /// the block. It always evaluates at least one, so the backlog keeps shrinking even in full
+    /// blocks. `on_idle` then evaluates as many more as fit in the remaining weight. Both resume
+    /// from a persistent cursor.
+    #[pallet::hooks]
+    impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let max_steps = Self::parameters().max_proposals_per_block;
+            Self::process_proposals(n, max_steps, 1, Self::initialize_weight_limit())
+                .saturating_add(T::DbWeight::get().reads(1))
+        }
+
+        fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::process_proposals(n, u32::MAX, 0, remaining_weight)
+        }
+
+        fn offchain_worker(n: BlockNumberFor<T>) {
+            Self::offchain_submit_closures(n);
+            Self::offchain_publish_results(n);
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::MaxDescriptionLength::get() <= MAX_DESCRIPTION_LENGTH,
+                "MaxDescriptionLength must not exceed MAX_DESCRIPTION_LENGTH"
+            );
+            assert!(
+                T::MinVotingPeriod::get() <= T::DefaultVotingPeriod::get() &&
+                    T::DefaultVotingPeriod::get() <= T::MaxVotingPeriod::get(),
+                "DefaultVotingPeriod must lie between MinVotingPeriod and MaxVotingPeriod"
+            );
+            assert!(
+                (1..=T::MaxActiveProposals::get()).contains(&T::MaxProposalsPerBlock::get()),
+                "MaxProposalsPerBlock must be between 1 and MaxActiveProposals"
+            );
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I> {
+        type Call = Call<T, I>;
+
+        /// Unsigned `close_proposal` transactions from the off-chain worker and relayed
+        /// `vote_signed` ballots are valid; all other unsigned calls are rejected.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            match call {
+                Call::close_proposal { proposal_id } => Self::validate_closure(*proposal_id),
+                Call::vote_signed { ballot, signature } => Self::validate_ballot(ballot, signature),
+                _ => InvalidTransaction::Call.into(),
+            }
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Unsigned closures are valid once the proposal's voting period has ended, and only one
+        /// per proposal and instance is kept in the pool.
+        fn validate_closure(proposal_id: ProposalId) -> TransactionValidity {
+            let proposal = Self::proposals(proposal_id).ok_or(InvalidTransaction::Stale)?;
+            ensure!(!proposal.is_closed, InvalidTransaction::Stale);
+
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block > proposal.end_block, InvalidTransaction::Future);
+
+            ValidTransaction::with_tag_prefix("SimpleGovernanceClose")
+                .priority(T::UnsignedPriority::get())
+                .and_provides((Self::index() as u32, proposal_id))
+                .longevity(T::UnsignedLongevity::get())
+                .propagate(true)
+                .build()
+        }
+
+        /// Relayed ballots are valid if signed by their voter for this chain and the vote can
+        /// still be cast. A ballot with a future nonce waits in the pool for the voter's earlier
+        /// ones, and only one ballot per voter and nonce is kept.
+        fn validate_ballot(
+            ballot: &BallotOf<T>,
+            signature: &T::BallotSignature,
+        ) -> TransactionValidity {
+            let nonce = BallotNonces::<T, I>::get(&ballot.voter);
+            ensure!(ballot.nonce >= nonce, InvalidTransaction::Stale);
+            ensure!(Self::is_valid_ballot(ballot, signature), InvalidTransaction::BadProof);
+
+            let proposal = Self::proposals(ballot.proposal_id).ok_or(InvalidTransaction::Stale)?;
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(
+                !proposal.is_closed && current_block <= proposal.end_block,
+                InvalidTransaction::Stale
+            );
+            ensure!(
+                !Votes::<T, I>::contains_key(ballot.proposal_id, &ballot.voter),
+                InvalidTransaction::Stale
+            );
+
+            let mut validity = ValidTransaction::with_tag_prefix("SimpleGovernanceBallot")
+                .priority(T::UnsignedPriority::get())
+                .and_provides((Self::index() as u32, &ballot.voter, ballot.nonce))
+                .longevity(T::UnsignedLongevity::get())
+                .propagate(true);
+            if ballot.nonce > nonce {
+                validity = validity
+                    .and_requires((Self::index() as u32, &ballot.voter, ballot.nonce - 1));
+            }
+            validity.build()
+        }
+
+        /// Whether a `vote` from `origin` on `proposal_id` is free of fees: it must be the
+        /// account's first vote on an open proposal, and fewer than `MaxFreeVotesPerBlock` free
+        /// votes may have been cast in the current block.
+        pub fn is_free_vote(origin: &OriginFor<T>, proposal_id: ProposalId) -> bool {
+            let Ok(who) = ensure_signed(origin.clone()) else {
+                return false;
+            };
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            Self::free_votes_in(now) < T::MaxFreeVotesPerBlock::get() &&
+                Self::proposals(proposal_id)
+                    .is_some_and(|proposal| !proposal.is_closed && now <= proposal.end_block) &&
+                !Votes::<T, I>::contains_key(proposal_id, &who)
+        }
+
+        /// Number of fee-free votes cast in block `now`.
+        fn free_votes_in(now: BlockNumberFor<T>) -> u32 {
+            FreeVotes::<T, I>::get()
+                .filter(|(block, _)| *block == now)
+                .map_or(0, |(_, count)| count)
+        }
+
+        /// The message voters sign for `vote_signed`: the ballot, prefixed with `BALLOT_CONTEXT`
+        /// and the pallet's index so it cannot be replayed on another instance.
+        pub fn ballot_payload(ballot: &BallotOf<T>) -> Vec<u8> {
+            (BALLOT_CONTEXT, Self::index() as u32, ballot).encode()
+        }
+
+        /// Whether `signature` is the voter's signature of `ballot`, made for this chain.
+        fn is_valid_ballot(ballot: &BallotOf<T>, signature: &T::BallotSignature) -> bool {
+            let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+            ballot.genesis_hash == genesis_hash &&
+                signature.verify(&Self::ballot_payload(ballot)[..], &ballot.voter)
+        }
+    }
+
+    /// Tasks that anyone can submit as unsigned `frame_system::do_task` extrinsics. Each task is
+    /// only valid while its condition holds, so they complement the hooks rather than race them.
+    #[pallet::tasks_experimental]
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Close an open proposal that has expired or already meets its track's curves.
+        #[pallet::task_list(ActiveProposals::<T, I>::get().into_iter())]
+        #[pallet::task_condition(|proposal_id| Pallet::<T, I>::closable_at(
+            proposal_id,
+            frame_system::Pallet::<T>::block_number(),
+        ).is_some())]
+        #[pallet::task_weight(Pallet::<T, I>::close_weight())]
+        #[pallet::task_index(0)]
+        pub fn close_expired_proposal(proposal_id: ProposalId) -> DispatchResult {
+            let now = <frame_system::Pallet<T>>::block_number();
+            let (proposal, reason, approved) =
+                Self::closable_at(proposal_id, now).ok_or(Error::<T, I>::ProposalNotClosable)?;
+
+            Self::do_close_proposal(proposal_id, proposal, reason, approved);
+
+            Ok(())
+        }
+
+        /// Remove up to `MaxVotesRemovedPerTask` individual votes of a closed proposal. The vote
+        /// tally is kept.
+        #[pallet::task_list(Proposals::<T, I>::iter_keys())]
+        #[pallet::task_condition(|proposal_id| Pallet::<T, I>::has_stale_votes(proposal_id))]
+        #[pallet::task_weight(T::WeightInfo::clean_up_votes(T::MaxVotesRemovedPerTask::get()))]
+        #[pallet::task_index(1)]
+        pub fn clean_up_votes(proposal_id: ProposalId) -> DispatchResult {
+            let proposal = Self::proposals(&proposal_id).ok_or(Error::<T, I>::ProposalNotFound)?;
+            ensure!(proposal.is_closed, Error::<T, I>::ProposalNotClosed);
+
+            let mut removed = 0u32;
+            for (voter, _) in Votes::<T, I>::drain_prefix(&proposal_id)
+                .take(T::MaxVotesRemovedPerTask::get() as usize)
+            {
+                VotesByAccount::<T, I>::remove(&voter, &proposal_id);
+                removed.saturating_inc();
+            }
+
+            Self::deposit_event(Event::VotesCleanedUp {
+                proposal_id,
+                removed,
+                complete: Votes::<T, I>::iter_prefix(&proposal_id).next().is_none(),
+            });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config<I>, I: 'static> Pallet<T, I> {
+        /// Record `who`'s vote on `proposal_id`, closing or extending the proposal if the vote
+        /// decides it or flips its outcome late. `base_weight` is the weight of the calling
+        /// extrinsic without closing the proposal and the hooks.
+        ///
+        /// If `may_close` is false, a vote that decides the proposal leaves it open, to be
+        /// closed by the auto-close hooks instead.
+        fn do_vote(
+            who: T::AccountId,
+            proposal_id: ProposalId,
+            vote: bool,
+            conviction: Conviction,
+            reason: Option<VoteReason<T::Hash>>,
+            may_close: bool,
+            base_weight: Weight,
+        ) -> DispatchResultWithPostInfo {
+            // Check if proposal exists
+            let mut proposal = Self::proposals(&proposal_id)
+                .ok_or(Error::<T, I>::ProposalNotFound)?;
+
+            // Check if proposal is closed
+            ensure!(!proposal.is_closed, Error::<T, I>::ProposalClosed);
+
+            // Check if voting period has ended
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            ensure!(current_block <= proposal.end_block, Error::<T, I>::VotingPeriodEnded);
+
+            // Check if account has already voted
+            ensure!(
+                !Votes::<T, I>::contains_key(&proposal_id, &who),
+                Error::<T, I>::AlreadyVoted
+            );
+
+            // Store the vote
+            let record = VoteRecord {
+                vote,
+                block: current_block,
+                balance: T::Currency::free_balance(&who),
+                conviction,
+                reason,
+            };
+            Votes::<T, I>::insert(&proposal_id, &who, &record);
+            VotesByAccount::<T, I>::insert(&who, &proposal_id, &record);
+            ParticipationOf::<T, I>::mutate(&who, |participation| {
+                if vote {
+                    participation.for_votes = participation.for_votes.saturating_add(1);
+                } else {
+                    participation.against_votes = participation.against_votes.saturating_add(1);
+                }
+            });
 
             // Update vote tally
-            VoteTallies::<T>::mutate(&proposal_id, |tally_opt| {
-                if let Some(tally) = tally_opt {
-                    if vote {
-                        tally.for_votes = tally.for_votes.saturating_add(1);
-                    } else {
-                        tally.against_votes = tally.against_votes.saturating_add(1);
-                    }
+            let (was_passing, tally) = VoteTallies::<T, I>::mutate(&proposal_id, |tally_opt| {
+                let tally = tally_opt.get_or_insert_with(VoteTally::default);
+                let was_passing = tally.is_passing();
+                if vote {
+                    tally.for_votes = tally.for_votes.saturating_add(1);
+                } else {
+                    tally.against_votes = tally.against_votes.saturating_add(1);
                 }
+                (was_passing, tally.clone())
             });
 
             // Emit event
             Self::deposit_event(Event::Voted {
                 proposal_id,
-                voter: who,
+                voter: who.clone(),
                 vote,
+                block: record.block,
+                balance: record.balance,
+                conviction: record.conviction,
+                reason: record.reason,
             });
 
-            Ok(())
+            let hooks_weight = T::GovernanceHooks::on_voted(proposal_id, &who, vote);
+
+            // Close the proposal right away if this vote decided it. Proposals with an action are
+            // only approved once their voting period has ended.
+            let approved = Self::meets_curves(&proposal, &tally, Perbill::one());
+            if may_close &&
+                Self::is_decided_early(&proposal, &tally) &&
+                !(approved && ProposalActions::<T, I>::contains_key(&proposal_id))
+            {
+                let close_weight = Self::do_close_proposal(
+                    proposal_id,
+                    proposal,
+                    CloseReason::EarlyDecision,
+                    approved,
+                );
+                let weight = base_weight
+                    .saturating_add(T::WeightInfo::close_proposal())
+                    .saturating_add(hooks_weight)
+                    .saturating_add(close_weight);
+                return Ok(Some(weight).into());
+            }
+
+            // Give everyone time to respond to a late swing in the outcome
+            let window = T::ConfirmationWindow::get();
+            if tally.is_passing() != was_passing &&
+                !window.is_zero() &&
+                current_block.saturating_add(window) > proposal.end_block &&
+                proposal.extensions < T::MaxDeadlineExtensions::get()
+            {
+                proposal.end_block = proposal.end_block.saturating_add(T::DeadlineExtension::get());
+                proposal.extensions = proposal.extensions.saturating_add(1);
+                Proposals::<T, I>::insert(&proposal_id, &proposal);
+
+                Self::deposit_event(Event::VotingPeriodExtended {
+                    proposal_id,
+                    end_block: proposal.end_block,
+                    extensions: proposal.extensions,
+                });
+            }
+
+            Ok(Some(base_weight.saturating_add(hooks_weight)).into())
         }
 
-        /// Manually close a proposal whose voting period has ended.
+        /// Create a proposal from `who` on `track`, enforcing the open-proposal limits.
         ///
-        /// Parameters:
-        /// - `origin`: The account closing the proposal
-        /// - `proposal_id`: The ID of the proposal to close
+        /// Returns the weight used by the governance hooks.
+        fn do_propose(
+            who: T::AccountId,
+            track: TrackId,
+            description: Vec<u8>,
+            action: Option<ProposalActionOf<T, I>>,
+        ) -> Result<Weight, DispatchError> {
+            ensure!(T::Tracks::info(track).is_some(), Error::<T, I>::UnknownTrack);
+
+            let parameters = Self::parameters();
+
+            // Validate description length against the configured maximum
+            ensure!(
+                description.len() <= parameters.max_description_length as usize,
+                Error::<T, I>::DescriptionTooLong
+            );
+
+            // Create bounded description with fixed size for storage
+            let bounded_description: BoundedVec<u8, ConstU32<256>> = 
+                description.try_into().map_err(|_| Error::<T, I>::DescriptionTooLong)?;
+
+            let proposal_id = Self::next_proposal_id();
+            let current_block = <frame_system::Pallet<T>>::block_number();
+            let end_block = current_block.saturating_add(parameters.voting_period);
+
+            // Enforce the per-account cooldown between proposals
+            let cooldown = T::ProposalCooldown::get();
+            if let Some(last_block) = Self::last_proposal_block(&who) {
+                ensure!(
+                    current_block >= last_block.saturating_add(cooldown),
+                    Error::<T, I>::ProposalCooldownActive
+                );
+            }
+
+            // Enforce the per-account limit on open proposals
+            let active_for_account = Self::active_proposals_of(&who);
+            ensure!(
+                active_for_account < T::MaxActiveProposalsPerAccount::get(),
+                Error::<T, I>::TooManyActiveProposalsForAccount
+            );
+
+            // Enforce the global limit on open proposals
+            let mut active_proposals = Self::active_proposals();
+            active_proposals
+                .try_push(proposal_id)
+                .map_err(|_| Error::<T, I>::TooManyActiveProposals)?;
+
+            let proposal = ProposalInfo {
+                proposer: who.clone(),
+                description: bounded_description.clone(),
+                start_block: current_block,
+                end_block,
+                is_closed: false,
+                extensions: 0,
+                track,
+                approved: false,
+                emergency: false,
+                close_reason: None,
+            };
+
+            // Store the proposal
+            Proposals::<T, I>::insert(&proposal_id, &proposal);
+            if let Some(action) = action {
+                ProposalActions::<T, I>::insert(&proposal_id, action);
+            }
+            
+            // Initialize vote tally
+            VoteTallies::<T, I>::insert(&proposal_id, VoteTally::default());
+
+            // Track the proposal as open
+            ActiveProposals::<T, I>::put(active_proposals);
+            ActiveProposalsOf::<T, I>::insert(&who, active_for_account.saturating_add(1));
+            LastProposalBlock::<T, I>::insert(&who, current_block);
+            
+            // Increment proposal ID for next proposal
+            NextProposalId::<T, I>::mutate(|id| *id = id.saturating_add(1));
+
+            // Emit event
+            Self::deposit_event(Event::ProposalCreated {
+                proposal_id,
+                proposer: who.clone(),
+                description: bounded_description,
+                end_block,
+                track,
+            });
+
+            Ok(T::GovernanceHooks::on_created(proposal_id, &who))
+        }
+
+        /// Close the open proposal `proposal_id` if its voting period has ended, approving it if
+        /// it meets its track's curves. If `may_dispatch` is false, an approved proposal that
+        /// dispatches a call is left open instead.
         ///
-        /// Emits `ProposalClosed` event on success.
-        #[pallet::call_index(2)]
-        #[pallet::weight(T::WeightInfo::close_proposal())]
-        pub fn close_proposal(
-            origin: OriginFor<T>,
+        /// Returns the weight used by the dispatched call, if any, and the hooks, and whether a
+        /// call was dispatched.
+        fn do_close_expired(
             proposal_id: ProposalId,
-        ) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
-
+            may_dispatch: bool,
+        ) -> Result<(Weight, bool), DispatchError> {
             // Check if proposal exists
-            let mut proposal = Self::proposals(&proposal_id)
-                .ok_or(Error::<T>::ProposalNotFound)?;
+            let proposal = Self::proposals(&proposal_id)
+                .ok_or(Error::<T, I>::ProposalNotFound)?;
 
             // Check if proposal is already closed
-            ensure!(!proposal.is_closed, Error::<T>::ProposalClosed);
+            ensure!(!proposal.is_closed, Error::<T, I>::ProposalClosed);
 
             // Check if voting period has ended
             let current_block = <frame_system::Pallet<T>>::block_number();
-            ensure!(current_block > proposal.end_block, Error::<T>::VotingPeriodNotEnded);
+            ensure!(current_block > proposal.end_block, Error::<T, I>::VotingPeriodNotEnded);
+
+            let tally = Self::vote_tallies(&proposal_id).unwrap_or_default();
+            let approved = Self::meets_curves(&proposal, &tally, Perbill::one());
+            let dispatches = approved &&
+                matches!(Self::proposal_actions(&proposal_id), Some(ProposalAction::Dispatch(_)));
+            ensure!(may_dispatch || !dispatches, Error::<T, I>::BatchWeightExhausted);
+
+            let weight =
+                Self::do_close_proposal(proposal_id, proposal, CloseReason::Expired, approved);
+            Ok((weight, dispatches))
+        }
 
-            // Close the proposal
+        /// Mark a proposal as closed with the given outcome, release its slot in the
+        /// open-proposal limits, emit `ProposalClosed` and notify the governance hooks.
+        ///
+        /// Returns the weight used by the dispatched call, if any, and the hooks.
+        fn do_close_proposal(
+            proposal_id: ProposalId,
+            mut proposal: ProposalInfo<T::AccountId, BlockNumberFor<T>>,
+            reason: CloseReason,
+            approved: bool,
+        ) -> Weight {
             proposal.is_closed = true;
-            Proposals::<T>::insert(&proposal_id, &proposal);
+            proposal.approved = approved;
+            proposal.close_reason = Some(reason);
+            Proposals::<T, I>::insert(&proposal_id, &proposal);
+
+            // Release the proposal's slot in the open-proposal limits
+            ActiveProposals::<T, I>::mutate(|ids| ids.retain(|id| *id != proposal_id));
+            ActiveProposalsOf::<T, I>::mutate_exists(&proposal.proposer, |count| {
+                *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+            });
 
             // Get vote tally for event
             let tally = Self::vote_tallies(&proposal_id).unwrap_or_default();
@@ -340,53 +2017,491 @@ pub mod pallet {
                 proposal_id,
                 for_votes: tally.for_votes,
                 against_votes: tally.against_votes,
+                reason,
+                approved,
+            });
+
+            let call_weight = if approved {
+                Self::enact_proposal(proposal_id, proposal.track)
+            } else {
+                Weight::zero()
+            };
+
+            call_weight.saturating_add(T::GovernanceHooks::on_closed(proposal_id, approved, reason))
+        }
+
+        /// Worst-case weight of closing a proposal: enacting it, including dispatching its call,
+        /// and notifying the hooks.
+        pub fn close_weight() -> Weight {
+            T::WeightInfo::close_proposal()
+                .saturating_add(T::MaxCallWeight::get())
+                .saturating_add(T::GovernanceHooks::max_weight())
+        }
+
+        /// Carry out the action of the approved proposal `proposal_id`, if it has one. A failed
+        /// action is rolled back and reported in `ProposalEnacted`.
+        ///
+        /// Returns the weight used by the dispatched call, if the action is one.
+        fn enact_proposal(proposal_id: ProposalId, track: TrackId) -> Weight {
+            let Some(action) = Self::proposal_actions(&proposal_id) else {
+                return Weight::zero();
+            };
+
+            let is_call = matches!(action, ProposalAction::Dispatch(_));
+            let mut call_weight = Weight::zero();
+            let result = with_storage_layer(|| {
+                Self::execute_action(proposal_id, track, action, &mut call_weight)
             });
+            // Only a call that worked under the governance origin proves governance can act
+            if is_call && result.is_ok() {
+                EnactedProposals::<T, I>::mutate(|count| *count = count.saturating_add(1));
+            }
+            Self::deposit_event(Event::ProposalEnacted { proposal_id, result });
+
+            call_weight
+        }
+
+        fn execute_action(
+            proposal_id: ProposalId,
+            track: TrackId,
+            action: ProposalActionOf<T, I>,
+            call_weight: &mut Weight,
+        ) -> DispatchResult {
+            match action {
+                ProposalAction::Spend { beneficiary, amount, vesting } => {
+                    T::Currency::transfer(
+                        &T::TreasuryAccount::get(),
+                        &beneficiary,
+                        amount,
+                        ExistenceRequirement::KeepAlive,
+                    )?;
+
+                    if let Some(vesting) = vesting {
+                        T::Vesting::add_vesting_schedule(
+                            &beneficiary,
+                            amount,
+                            vesting.per_block,
+                            vesting.starting_block,
+                        )?;
+                    }
+
+                    Ok(())
+                },
+                ProposalAction::AuthorizeUpgrade { code_hash, .. } => {
+                    // Let `apply_authorized_upgrade` reject code that does not increase the
+                    // spec version
+                    frame_system::Pallet::<T>::do_authorize_upgrade(code_hash, true);
+
+                    Ok(())
+                },
+                ProposalAction::RemoveSudoKey => {
+                    // Never give up sudo before governance has dispatched a call successfully
+                    ensure!(EnactedProposals::<T, I>::get() > 0, Error::<T, I>::NoProposalEnacted);
+                    ensure!(T::SudoKey::exists(), Error::<T, I>::NoSudoKey);
+
+                    T::SudoKey::remove()
+                },
+                ProposalAction::ChangeCommittee(MembershipChange::Add(who)) => {
+                    T::Committee::add_member(&who)
+                },
+                ProposalAction::ChangeCommittee(MembershipChange::Remove(who)) => {
+                    T::Committee::remove_member(&who)
+                },
+                ProposalAction::ChangeValidators(MembershipChange::Add(who)) => {
+                    T::Validators::add_member(&who)
+                },
+                ProposalAction::ChangeValidators(MembershipChange::Remove(who)) |
+                ProposalAction::RemoveOffender(who) => T::Validators::remove_member(&who),
+                ProposalAction::Dispatch(call) => {
+                    let (result, weight) = Self::dispatch_call(proposal_id, track, &call);
+                    *call_weight = weight;
+
+                    result
+                },
+            }
+        }
+
+        /// Decode `call` and dispatch it with the governance origin of the approved proposal
+        /// `proposal_id`.
+        ///
+        /// Returns the dispatch result and the weight used by the call.
+        fn dispatch_call(
+            proposal_id: ProposalId,
+            track: TrackId,
+            call: &[u8],
+        ) -> (DispatchResult, Weight) {
+            let Ok(call) = <T as Config<I>>::ProposalCall::decode(&mut &call[..]) else {
+                return (Err(Error::<T, I>::UndecodableCall.into()), Weight::zero());
+            };
+
+            // The call's weight may have grown in a runtime upgrade since it was proposed
+            let info = call.get_dispatch_info();
+            if !info.call_weight.all_lte(T::MaxCallWeight::get()) {
+                return (Err(Error::<T, I>::CallTooHeavy.into()), Weight::zero());
+            }
+
+            let origin = GovernanceOrigin::Approved { proposal_id, track };
+            let result = call.dispatch(origin.into());
+            let post_info = match &result {
+                Ok(post_info) => post_info,
+                Err(e) => &e.post_info,
+            };
+            let weight = post_info.actual_weight.unwrap_or(info.call_weight);
+
+            (result.map(|_| ()).map_err(|e| e.error), weight)
+        }
+
+        /// Dispatch the call of proposal `proposal_id` as if it had been approved, then roll
+        /// back all of its effects. Used by the `GovernanceApi::dry_run` runtime API.
+        ///
+        /// Returns the dispatch result, the events the call emitted, the weight it used and the
+        /// storage roots before and after the dispatch.
+        pub fn dry_run(
+            proposal_id: ProposalId,
+        ) -> Result<DryRunEffects<<T as frame_system::Config>::RuntimeEvent, T::Hash>, DryRunError>
+        {
+            let proposal = Self::proposals(&proposal_id).ok_or(DryRunError::ProposalNotFound)?;
+            let Some(ProposalAction::Dispatch(call)) = Self::proposal_actions(&proposal_id) else {
+                return Err(DryRunError::NotACallProposal);
+            };
+
+            let root_before = Self::storage_root();
+            let event_count = frame_system::Pallet::<T>::event_count() as usize;
+
+            with_transaction(|| {
+                let (result, weight) = Self::dispatch_call(proposal_id, proposal.track, &call);
+                let events = frame_system::Pallet::<T>::read_events_no_consensus()
+                    .skip(event_count)
+                    .map(|record| record.event)
+                    .collect();
+                let storage = StorageDiffSummary { root_before, root_after: Self::storage_root() };
+
+                TransactionOutcome::Rollback(Ok::<_, DispatchError>(DryRunEffects {
+                    result,
+                    events,
+                    weight,
+                    storage,
+                }))
+            })
+            .map_err(|_| DryRunError::TransactionLimitReached)
+        }
+
+        /// Root of the current state, including uncommitted changes.
+        fn storage_root() -> T::Hash {
+            let version = <T as frame_system::Config>::Version::get().state_version();
+            T::Hash::decode(&mut &sp_io::storage::root(version)[..])
+                .expect("Node is configured to use the same hash; qed")
+        }
+
+        /// Check `code` against the runtime upgrade proposal `proposal_id`: its hash must be the
+        /// authorized one and its `spec_version` the one announced to voters.
+        pub fn verify_upgrade(proposal_id: ProposalId, code: &[u8]) -> DispatchResult {
+            let Some(ProposalAction::AuthorizeUpgrade { code_hash, spec_version }) =
+                Self::proposal_actions(&proposal_id)
+            else {
+                return Err(Error::<T, I>::NotAnUpgradeProposal.into());
+            };
+
+            ensure!(T::Hashing::hash(code) == code_hash, Error::<T, I>::UpgradeCodeHashMismatch);
+
+            let version = sp_io::misc::runtime_version(code)
+                .and_then(|version| RuntimeVersion::decode(&mut &version[..]).ok())
+                .ok_or(Error::<T, I>::UpgradeVersionUnreadable)?;
+            ensure!(
+                version.spec_version == spec_version,
+                Error::<T, I>::UpgradeSpecVersionMismatch
+            );
 
             Ok(())
         }
-    }
 
-    /// Hook that runs at the beginning of each block.
-    #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
-            let mut weight = Weight::zero();
-            let mut closed_count = 0u32;
-            let max_closures = T::MaxProposalsPerBlock::get();
+        /// Up to `limit` votes of `who`, at most `MAX_VOTES_PAGE`, in storage order. Pass the
+        /// last proposal ID of a page as `start_after` to get the next one.
+        pub fn votes_of(
+            who: &T::AccountId,
+            start_after: Option<ProposalId>,
+            limit: u32,
+        ) -> Vec<(ProposalId, VoteRecordOf<T, I>)> {
+            let limit = limit.min(MAX_VOTES_PAGE) as usize;
+            match start_after {
+                Some(proposal_id) => VotesByAccount::<T, I>::iter_prefix_from(
+                    who,
+                    VotesByAccount::<T, I>::hashed_key_for(who, proposal_id),
+                )
+                .take(limit)
+                .collect(),
+                None => VotesByAccount::<T, I>::iter_prefix(who).take(limit).collect(),
+            }
+        }
+
+        /// How many votes `who` has cast and which share of all proposals they voted on. The
+        /// share is zero while there are no proposals.
+        pub fn participation(who: &T::AccountId) -> ParticipationStats {
+            let votes = ParticipationOf::<T, I>::get(who);
+            let proposals = NextProposalId::<T, I>::get();
+            let cast = votes.for_votes.saturating_add(votes.against_votes);
+            let rate = if proposals.is_zero() {
+                Perbill::zero()
+            } else {
+                Perbill::from_rational(cast, proposals)
+            };
+
+            ParticipationStats { votes, proposals, rate }
+        }
+
+        /// Whether a closed proposal still has individual votes in storage.
+        pub fn has_stale_votes(proposal_id: ProposalId) -> bool {
+            Self::proposals(&proposal_id).is_some_and(|proposal| proposal.is_closed) &&
+                Votes::<T, I>::iter_prefix(&proposal_id).next().is_some()
+        }
+
+        /// Weight `on_initialize` may use to close proposals: `MAX_INITIALIZE_SHARE` of the
+        /// block, less the weight the block has already used.
+        fn initialize_weight_limit() -> Weight {
+            let max_block = <T as frame_system::Config>::BlockWeights::get().max_block;
+            let used = <frame_system::Pallet<T>>::block_weight().total();
+            (MAX_INITIALIZE_SHARE * max_block).saturating_sub(used)
+        }
+
+        /// Evaluate up to `max_steps` open proposals, starting after the process cursor, and
+        /// close those that have expired or already meet their track's curves.
+        ///
+        /// Each proposal is first checked for closability at read cost; the weight of closing
+        /// it is only reserved if it is to be closed. Stops before a check or closure that would
+        /// exceed `weight_limit`, except within the first `min_steps` proposals, and returns the
+        /// weight used. A proposal left open for lack of weight is evaluated again next time.
+        fn process_proposals(
+            now: BlockNumberFor<T>,
+            max_steps: u32,
+            min_steps: u32,
+            weight_limit: Weight,
+        ) -> Weight {
+            let db_weight = T::DbWeight::get();
+            let mut weight = db_weight.reads_writes(2, 1);
+            if min_steps.is_zero() && weight.any_gt(weight_limit) {
+                return Weight::zero();
+            }
+
+            let active = Self::active_proposals();
+            if active.is_empty() {
+                return db_weight.reads(2);
+            }
 
-            // Iterate through proposals and auto-close expired ones
-            for (proposal_id, mut proposal) in Proposals::<T>::iter() {
-                if closed_count >= max_closures {
+            // Resume with the first open proposal after the cursor, wrapping around
+            let start = Self::process_cursor()
+                .and_then(|cursor| active.iter().position(|id| *id > cursor))
+                .unwrap_or(0);
+            let steps = max_steps.min(active.len() as u32) as usize;
+            let check_weight = db_weight.reads(2);
+
+            let mut cursor = None;
+            for offset in 0..steps {
+                let forced = (offset as u32) < min_steps;
+                if !forced && weight.saturating_add(check_weight).any_gt(weight_limit) {
                     break;
                 }
 
-                if !proposal.is_closed && n > proposal.end_block {
-                    // Close the proposal
-                    proposal.is_closed = true;
-                    Proposals::<T>::insert(&proposal_id, &proposal);
-
-                    // Get vote tally for event
-                    let tally = Self::vote_tallies(&proposal_id).unwrap_or_default();
+                let proposal_id = active[(start + offset) % active.len()];
+                weight = weight.saturating_add(check_weight);
 
-                    // Emit event
-                    Self::deposit_event(Event::ProposalClosed {
-                        proposal_id,
-                        for_votes: tally.for_votes,
-                        against_votes: tally.against_votes,
-                    });
+                if let Some((proposal, reason, approved)) = Self::closable_at(proposal_id, now) {
+                    let reserved = Self::closure_weight(proposal_id, approved);
+                    if !forced && weight.saturating_add(reserved).any_gt(weight_limit) {
+                        break;
+                    }
 
-                    closed_count = closed_count.saturating_add(1);
-                    weight = weight.saturating_add(T::WeightInfo::close_proposal());
+                    let close_weight =
+                        Self::do_close_proposal(proposal_id, proposal, reason, approved);
+                    weight = weight
+                        .saturating_add(T::WeightInfo::close_proposal())
+                        .saturating_add(close_weight);
+                    if approved {
+                        weight = weight.saturating_add(db_weight.reads(1));
+                    }
                 }
+
+                cursor = Some(proposal_id);
+            }
+
+            if cursor.is_some() {
+                ProcessCursor::<T, I>::set(cursor);
             }
 
             weight
         }
+
+        /// Worst-case weight of closing `proposal_id` with the given outcome. The call weight is
+        /// only reserved for approved proposals that dispatch a call, which takes a read to
+        /// find out.
+        fn closure_weight(proposal_id: ProposalId, approved: bool) -> Weight {
+            let weight =
+                T::WeightInfo::close_proposal().saturating_add(T::GovernanceHooks::max_weight());
+            if !approved {
+                return weight;
+            }
+
+            let weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            match Self::proposal_actions(&proposal_id) {
+                Some(ProposalAction::Dispatch(_)) => weight.saturating_add(T::MaxCallWeight::get()),
+                _ => weight,
+            }
+        }
+
+        /// The open proposal `proposal_id` together with why and with which outcome it should be
+        /// closed at block `now`, or `None` if it should stay open.
+        pub fn closable_at(
+            proposal_id: ProposalId,
+            now: BlockNumberFor<T>,
+        ) -> Option<(ProposalInfo<T::AccountId, BlockNumberFor<T>>, CloseReason, bool)> {
+            let proposal = Self::proposals(&proposal_id).filter(|proposal| !proposal.is_closed)?;
+            let tally = Self::vote_tallies(&proposal_id).unwrap_or_default();
+
+            if now > proposal.end_block {
+                let approved = Self::meets_curves(&proposal, &tally, Perbill::one());
+                Some((proposal, CloseReason::Expired, approved))
+            } else if Self::is_approved_early(&proposal, &tally, now) &&
+                !ProposalActions::<T, I>::contains_key(&proposal_id)
+            {
+                Some((proposal, CloseReason::EarlyDecision, true))
+            } else {
+                None
+            }
+        }
+
+        /// Whether `tally` meets the approval and support curves of the proposal's track once
+        /// `elapsed` of the voting period has passed.
+        ///
+        /// Support is measured against `EligibleElectorate` and is not required when the
+        /// electorate is unknown (zero).
+        pub fn meets_curves(
+            proposal: &ProposalInfo<T::AccountId, BlockNumberFor<T>>,
+            tally: &VoteTally,
+            elapsed: Perbill,
+        ) -> bool {
+            let Some(track) = T::Tracks::info(proposal.track) else {
+                return false;
+            };
+
+            let cast = tally.for_votes.saturating_add(tally.against_votes);
+            if cast.is_zero() {
+                return false;
+            }
+
+            let approval = Perbill::from_rational(tally.for_votes, cast);
+            if approval < track.min_approval.threshold(elapsed) {
+                return false;
+            }
+
+            let electorate = T::EligibleElectorate::get();
+            electorate.is_zero() ||
+                Perbill::from_rational(tally.for_votes, electorate) >=
+                    track.min_support.threshold(elapsed)
+        }
+
+        /// Whether an open proposal already meets its track's curves at block `now`.
+        ///
+        /// Disabled unless `EarlyApproval` is set. Also requires a known electorate, since
+        /// without support a single vote in favour would be enough to pass.
+        pub fn is_approved_early(
+            proposal: &ProposalInfo<T::AccountId, BlockNumberFor<T>>,
+            tally: &VoteTally,
+            now: BlockNumberFor<T>,
+        ) -> bool {
+            if !T::EarlyApproval::get() || T::EligibleElectorate::get().is_zero() {
+                return false;
+            }
+
+            Self::meets_curves(proposal, tally, Self::elapsed(proposal, now))
+        }
+
+        /// The share of the proposal's voting period that has passed at block `now`.
+        fn elapsed(
+            proposal: &ProposalInfo<T::AccountId, BlockNumberFor<T>>,
+            now: BlockNumberFor<T>,
+        ) -> Perbill {
+            let period: u64 =
+                proposal.end_block.saturating_sub(proposal.start_block).unique_saturated_into();
+            let passed: u64 = now.saturating_sub(proposal.start_block).unique_saturated_into();
+            if period.is_zero() {
+                return Perbill::one();
+            }
+            Perbill::from_rational(passed.min(period), period)
+        }
+
+        /// Whether `tally` already decides `proposal` before the end block.
+        ///
+        /// This is the case when the votes not yet cast by the eligible electorate can no longer
+        /// change whether the final tally meets the track's curves, or when either side has
+        /// reached the `EarlyDecisionThreshold` share of the electorate.
+        pub fn is_decided_early(
+            proposal: &ProposalInfo<T::AccountId, BlockNumberFor<T>>,
+            tally: &VoteTally,
+        ) -> bool {
+            let Some(threshold) = T::EarlyDecisionThreshold::get() else {
+                return false;
+            };
+
+            let electorate = T::EligibleElectorate::get();
+            let cast = tally.for_votes.saturating_add(tally.against_votes);
+            // More votes than eligible voters means the electorate is misconfigured
+            if electorate.is_zero() || cast > electorate {
+                return false;
+            }
+
+            // Compare the final tallies in which every remaining voter sides with either outcome
+            let remaining = electorate.saturating_sub(cast);
+            let best = VoteTally {
+                for_votes: tally.for_votes.saturating_add(remaining),
+                against_votes: tally.against_votes,
+            };
+            let worst = VoteTally {
+                for_votes: tally.for_votes,
+                against_votes: tally.against_votes.saturating_add(remaining),
+            };
+            let outcome_fixed = Self::meets_curves(proposal, &best, Perbill::one()) ==
+                Self::meets_curves(proposal, &worst, Perbill::one());
+
+            let supermajority = threshold.mul_ceil(electorate);
+            outcome_fixed || tally.for_votes >= supermajority || tally.against_votes >= supermajority
+        }
     }
 
     /// Type alias for proposal IDs.
     pub type ProposalId = u32;
 
+    /// Longest description a proposal can store.
+    pub const MAX_DESCRIPTION_LENGTH: u32 = 256;
+
+    /// Longest encoded call a proposal can dispatch.
+    pub const MAX_CALL_LENGTH: u32 = 1024;
+
+    /// Context prefixed to the ballots signed for `vote_signed`.
+    pub const BALLOT_CONTEXT: &[u8] = b"governance-ballot";
+
+    /// Longest reason text a vote can store.
+    pub const MAX_REASON_LENGTH: u32 = 128;
+
+    /// Most votes `Pallet::votes_of` returns at once.
+    pub const MAX_VOTES_PAGE: u32 = 256;
+
+    /// Share of the block's weight `on_initialize` may use to close proposals, which can
+    /// dispatch their calls. Proposals that do not fit are left to `on_idle`.
+    pub const MAX_INITIALIZE_SHARE: Perbill = Perbill::from_percent(25);
+
+    /// The origin approved proposals dispatch their calls with.
+    #[derive(
+        PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(I))]
+    #[codec(mel_bound())]
+    pub enum GovernanceOrigin<I> {
+        /// Proposal `proposal_id` on track `track` was approved.
+        Approved { proposal_id: ProposalId, track: TrackId },
+        /// Dummy variant carrying the instance.
+        _Phantom(PhantomData<I>),
+    }
+
     /// Information about a governance proposal.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct ProposalInfo<AccountId, BlockNumber> 
@@ -404,6 +2519,29 @@ pub mod pallet {
         pub end_block: BlockNumber,
         /// Whether the proposal has been closed.
         pub is_closed: bool,
+        /// How many times the voting period has been extended.
+        pub extensions: u32,
+        /// The track whose curves decide the proposal.
+        pub track: TrackId,
+        /// Whether the proposal was approved. Only meaningful once it is closed.
+        pub approved: bool,
+        /// Whether the proposal was fast-tracked or enacted instantly.
+        pub emergency: bool,
+        /// Why the proposal was closed, once it is.
+        pub close_reason: Option<CloseReason>,
+    }
+
+    /// Why a proposal was closed.
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum CloseReason {
+        /// The voting period ended.
+        Expired,
+        /// The outcome was decided by the eligible electorate before the voting period ended.
+        EarlyDecision,
+        /// `InstantOrigin` approved and enacted the proposal.
+        Instant,
+        /// `CancelOrigin` cancelled the proposal.
+        Cancelled,
     }
 
     /// Vote tally for a proposal.
@@ -415,36 +2553,227 @@ pub mod pallet {
         pub against_votes: u32,
     }
 
+    impl VoteTally {
+        /// Whether the proposal would pass if it closed now. A tie does not pass.
+        pub fn is_passing(&self) -> bool {
+            self.for_votes > self.against_votes
+        }
+    }
+
+    /// A vote as recorded in `Votes` and `VotesByAccount`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct VoteRecord<Balance, BlockNumber, Hash> {
+        /// The vote (true = for, false = against).
+        pub vote: bool,
+        /// The block the vote was cast in.
+        pub block: BlockNumber,
+        /// The voter's free balance when the vote was cast. The tally counts one vote per
+        /// account regardless.
+        pub balance: Balance,
+        /// The conviction the voter declared.
+        pub conviction: Conviction,
+        /// Why the voter voted the way they did, if they said.
+        pub reason: Option<VoteReason<Hash>>,
+    }
+
+    /// A vote signed off-chain by `voter`, to be relayed with `vote_signed`.
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo,
+    )]
+    pub struct Ballot<AccountId, Hash> {
+        /// The account casting the vote.
+        pub voter: AccountId,
+        /// The proposal to vote on.
+        pub proposal_id: ProposalId,
+        /// The vote (true = for, false = against).
+        pub vote: bool,
+        /// The voter's current ballot nonce.
+        pub nonce: u32,
+        /// The genesis hash of the chain the ballot is meant for.
+        pub genesis_hash: Hash,
+    }
+
+    /// How strongly a voter backs their vote, on the scale of `pallet_conviction_voting`. Only
+    /// recorded; it neither locks funds nor changes the tally.
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo,
+        MaxEncodedLen, Default,
+    )]
+    pub enum Conviction {
+        /// No conviction.
+        #[default]
+        None,
+        /// Backed as if locked for one enactment period.
+        Locked1x,
+        /// Backed as if locked for two enactment periods.
+        Locked2x,
+        /// Backed as if locked for four enactment periods.
+        Locked3x,
+        /// Backed as if locked for eight enactment periods.
+        Locked4x,
+        /// Backed as if locked for 16 enactment periods.
+        Locked5x,
+        /// Backed as if locked for 32 enactment periods.
+        Locked6x,
+    }
+
+    /// The reason given for a vote.
+    #[derive(
+        Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum VoteReason<Hash> {
+        /// The reason, as text.
+        Text(BoundedVec<u8, ConstU32<MAX_REASON_LENGTH>>),
+        /// The hash of a reason published elsewhere, e.g. on IPFS.
+        Hash(Hash),
+    }
+
+    /// An account's participation in governance.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct ParticipationStats {
+        /// Number of votes the account cast for and against proposals.
+        pub votes: VoteTally,
+        /// Number of proposals created so far.
+        pub proposals: u32,
+        /// Share of all proposals the account voted on.
+        pub rate: Perbill,
+    }
+
+    /// What an approved proposal does.
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ProposalAction<AccountId, Balance, BlockNumber, Hash> {
+        /// Pay `amount` from the treasury pot to `beneficiary`, optionally vested.
+        Spend {
+            beneficiary: AccountId,
+            amount: Balance,
+            vesting: Option<SpendVesting<Balance, BlockNumber>>,
+        },
+        /// Authorize upgrading the runtime to the code with hash `code_hash`, announced to
+        /// voters as `spec_version`.
+        AuthorizeUpgrade {
+            code_hash: Hash,
+            spec_version: u32,
+        },
+        /// Remove the chain's sudo key.
+        RemoveSudoKey,
+        /// Change the members of the committee.
+        ChangeCommittee(MembershipChange<AccountId>),
+        /// Change the validator set, from the next session on.
+        ChangeValidators(MembershipChange<AccountId>),
+        /// Remove a validator reported for an offence, from the next session on.
+        RemoveOffender(AccountId),
+        /// Dispatch the encoded call with `GovernanceOrigin::Approved`.
+        Dispatch(BoundedVec<u8, ConstU32<MAX_CALL_LENGTH>>),
+    }
+
+    /// A change to the members of the committee or the validator set.
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum MembershipChange<AccountId> {
+        /// Add a member.
+        Add(AccountId),
+        /// Remove a member.
+        Remove(AccountId),
+    }
+
+    /// Vesting schedule of a spend. The whole amount is locked and `per_block` of it unlocks
+    /// every block from `starting_block` on.
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct SpendVesting<Balance, BlockNumber> {
+        /// Amount unlocked per block.
+        pub per_block: Balance,
+        /// Block from which the funds start to unlock.
+        pub starting_block: BlockNumber,
+    }
+
+    /// Governance parameters that can be changed on-chain with `set_parameters`.
+    #[derive(Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct GovernanceParameters<BlockNumber> {
+        /// Maximum length of a proposal description.
+        pub max_description_length: u32,
+        /// Voting period of new proposals in blocks.
+        pub voting_period: BlockNumber,
+        /// Number of open proposals evaluated for auto-closing in `on_initialize`.
+        pub max_proposals_per_block: u32,
+    }
+
+    /// The effects of dry-running a call proposal with `Pallet::dry_run`. All of them have been
+    /// rolled back.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct DryRunEffects<Event, Hash> {
+        /// The result of dispatching the call.
+        pub result: DispatchResult,
+        /// The events emitted by the call.
+        pub events: Vec<Event>,
+        /// The weight used by the call.
+        pub weight: Weight,
+        /// How the call changed storage.
+        pub storage: StorageDiffSummary<Hash>,
+    }
+
+    /// Summary of the storage changes of a dry-run call: the state roots before and after it.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct StorageDiffSummary<Hash> {
+        /// State root before dispatching the call.
+        pub root_before: Hash,
+        /// State root after dispatching the call, before the changes were rolled back.
+        pub root_after: Hash,
+    }
+
+    impl<Hash: PartialEq> StorageDiffSummary<Hash> {
+        /// Whether the call changed storage.
+        pub fn changed(&self) -> bool {
+            self.root_before != self.root_after
+        }
+    }
+
+    /// Why a proposal could not be dry-run.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum DryRunError {
+        /// The proposal does not exist.
+        ProposalNotFound,
+        /// The proposal does not dispatch a call.
+        NotACallProposal,
+        /// The call could not be dispatched in a new transactional layer.
+        TransactionLimitReached,
+    }
+
     /// Genesis configuration for the pallet.
     #[pallet::genesis_config]
-    pub struct GenesisConfig<T: Config> {
+    pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
         /// Initial proposals to create at genesis.
         pub proposals: Vec<(T::AccountId, Vec<u8>)>,
+        #[serde(skip)]
+        pub _phantom: PhantomData<I>,
     }
 
-    impl<T: Config> Default for GenesisConfig<T> {
+    impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
         fn default() -> Self {
             Self {
                 proposals: Default::default(),
+                _phantom: Default::default(),
             }
         }
     }
 
     #[pallet::genesis_build]
-    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+    impl<T: Config<I>, I: 'static> BuildGenesisConfig for GenesisConfig<T, I> {
         fn build(&self) {
+            let parameters = Pallet::<T, I>::parameters();
             for (proposer, description) in &self.proposals {
                 // Validate description length against the configured maximum
-                if description.len() > T::MaxDescriptionLength::get() as usize {
+                if description.len() > parameters.max_description_length as usize {
                     panic!("Description too long in genesis config");
                 }
 
                 let bounded_description: BoundedVec<u8, ConstU32<256>> = 
                     description.clone().try_into().expect("Description too long in genesis config");
 
-                let proposal_id = NextProposalId::<T>::get();
+                let proposal_id = NextProposalId::<T, I>::get();
+                let track = T::Tracks::default_track()
+                    .expect("At least one track must be configured for genesis proposals");
                 let current_block = BlockNumberFor::<T>::zero();
-                let end_block = current_block.saturating_add(T::DefaultVotingPeriod::get());
+                let end_block = current_block.saturating_add(parameters.voting_period);
 
                 let proposal = ProposalInfo {
                     proposer: proposer.clone(),
@@ -452,17 +2781,27 @@ pub mod pallet {
                     start_block: current_block,
                     end_block,
                     is_closed: false,
+                    extensions: 0,
+                    track,
+                    approved: false,
+                    emergency: false,
+                    close_reason: None,
                 };
 
-                Proposals::<T>::insert(&proposal_id, &proposal);
-                VoteTallies::<T>::insert(&proposal_id, VoteTally::default());
-                NextProposalId::<T>::mutate(|id| *id = id.saturating_add(1));
+                Proposals::<T, I>::insert(&proposal_id, &proposal);
+                VoteTallies::<T, I>::insert(&proposal_id, VoteTally::default());
+                ActiveProposals::<T, I>::try_append(proposal_id)
+                    .expect("Too many proposals in genesis config");
+                ActiveProposalsOf::<T, I>::mutate(proposer, |count| *count = count.saturating_add(1));
+                LastProposalBlock::<T, I>::insert(proposer, current_block);
+                NextProposalId::<T, I>::mutate(|id| *id = id.saturating_add(1));
 
-                Pallet::<T>::deposit_event(Event::ProposalCreated {
+                Pallet::<T, I>::deposit_event(Event::ProposalCreated {
                     proposal_id,
                     proposer: proposer.clone(),
                     description: bounded_description,
                     end_block,
+                    track,
                 });
             }
         }
@@ -0,0 +1,393 @@
+//! Storage migrations.
+//!
+//! Every change to the layout of the pallet's storage bumps [`STORAGE_VERSION`] and comes with
+//! a migration to the new version. The migrations are wrapped in [`VersionedMigration`], so
+//! each only runs on a chain at the version it migrates from and bumps the version when done.
+//! Runtimes add [`MigrateToLatest`] to their migrations for every instance of the pallet.
+//!
+//! [`STORAGE_VERSION`]: crate::STORAGE_VERSION
+
+use crate::pallet::*;
+use crate::{TrackId, TracksInfo};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+    migrations::VersionedMigration,
+    storage_alias,
+    traits::{ConstU32, Currency, Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+    Blake2_128Concat, BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Saturating;
+#[cfg(feature = "try-runtime")]
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Migrates the storage of the pallet from any earlier version to the current one.
+pub type MigrateToLatest<T, I = ()> = (
+    v1::MigrateToV1<T, I>,
+    v2::MigrateToV2<T, I>,
+    v3::MigrateToV3<T, I>,
+    v4::MigrateToV4<T, I>,
+);
+
+/// The number of proposals before a migration, to check that none was lost.
+#[cfg(feature = "try-runtime")]
+fn proposal_count<T: Config<I>, I: 'static>() -> Vec<u8> {
+    (Proposals::<T, I>::iter_keys().count() as u32).encode()
+}
+
+/// Layouts of version 0, before any migration.
+pub mod v0 {
+    use super::*;
+
+    /// A proposal before the voting period could be extended.
+    #[derive(Encode, Decode)]
+    pub struct ProposalInfo<AccountId, BlockNumber> {
+        pub proposer: AccountId,
+        pub description: BoundedVec<u8, ConstU32<256>>,
+        pub start_block: BlockNumber,
+        pub end_block: BlockNumber,
+        pub is_closed: bool,
+    }
+}
+
+/// Version 1 adds `ProposalInfo::extensions`.
+pub mod v1 {
+    use super::*;
+
+    /// A proposal before it was decided on a track.
+    #[derive(Encode, Decode)]
+    pub struct ProposalInfo<AccountId, BlockNumber> {
+        pub proposer: AccountId,
+        pub description: BoundedVec<u8, ConstU32<256>>,
+        pub start_block: BlockNumber,
+        pub end_block: BlockNumber,
+        pub is_closed: bool,
+        pub extensions: u32,
+    }
+
+    /// `Proposals` in the layout of version 1.
+    #[storage_alias]
+    pub type Proposals<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        ProposalId,
+        ProposalInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+    >;
+
+    /// Records that no existing proposal has been extended.
+    pub struct UncheckedMigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Proposals::<T, I>::translate::<v0::ProposalInfo<T::AccountId, BlockNumberFor<T>>, _>(
+                |_, old| {
+                    translated.saturating_inc();
+                    Some(ProposalInfo {
+                        proposer: old.proposer,
+                        description: old.description,
+                        start_block: old.start_block,
+                        end_block: old.end_block,
+                        is_closed: old.is_closed,
+                        extensions: 0,
+                    })
+                },
+            );
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(proposal_count::<T, I>())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+            frame_support::ensure!(
+                Proposals::<T, I>::iter_values().count() as u32 == count,
+                "proposals were lost migrating to v1"
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrates from version 0 to 1.
+    pub type MigrateToV1<T, I = ()> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateToV1<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 2 adds `ProposalInfo::track` and `ProposalInfo::approved`.
+pub mod v2 {
+    use super::*;
+
+    /// A proposal before it could be fast-tracked.
+    #[derive(Encode, Decode)]
+    pub struct ProposalInfo<AccountId, BlockNumber> {
+        pub proposer: AccountId,
+        pub description: BoundedVec<u8, ConstU32<256>>,
+        pub start_block: BlockNumber,
+        pub end_block: BlockNumber,
+        pub is_closed: bool,
+        pub extensions: u32,
+        pub track: TrackId,
+        pub approved: bool,
+    }
+
+    /// `Proposals` in the layout of version 2.
+    #[storage_alias]
+    pub type Proposals<T: Config<I>, I: 'static> = StorageMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        ProposalId,
+        ProposalInfo<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>,
+    >;
+
+    /// Puts existing proposals on the default track. Closed ones are approved if they had more
+    /// votes for than against, which is how they were decided before tracks.
+    pub struct UncheckedMigrateToV2<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV2<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let track = T::Tracks::default_track().unwrap_or_default();
+            let mut translated = 0u64;
+            Proposals::<T, I>::translate::<v1::ProposalInfo<T::AccountId, BlockNumberFor<T>>, _>(
+                |proposal_id, old| {
+                    translated.saturating_inc();
+                    let approved = old.is_closed &&
+                        VoteTallies::<T, I>::get(proposal_id)
+                            .is_some_and(|tally| tally.is_passing());
+                    Some(ProposalInfo {
+                        proposer: old.proposer,
+                        description: old.description,
+                        start_block: old.start_block,
+                        end_block: old.end_block,
+                        is_closed: old.is_closed,
+                        extensions: old.extensions,
+                        track,
+                        approved,
+                    })
+                },
+            );
+            T::DbWeight::get().reads_writes(translated.saturating_mul(2), translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(proposal_count::<T, I>())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+            let mut migrated = 0u32;
+            for proposal in Proposals::<T, I>::iter_values() {
+                frame_support::ensure!(
+                    proposal.is_closed || !proposal.approved,
+                    "an open proposal was approved migrating to v2"
+                );
+                migrated.saturating_inc();
+            }
+            frame_support::ensure!(migrated == count, "proposals were lost migrating to v2");
+            Ok(())
+        }
+    }
+
+    /// Migrates from version 1 to 2.
+    pub type MigrateToV2<T, I = ()> = VersionedMigration<
+        1,
+        2,
+        UncheckedMigrateToV2<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 3 adds `ProposalInfo::emergency` and `ProposalInfo::close_reason`.
+pub mod v3 {
+    use super::*;
+
+    /// A vote in `VotesByAccount` before it recorded the voter's balance. `Votes` only held
+    /// the vote itself.
+    #[derive(Encode, Decode)]
+    pub struct VoteRecord<BlockNumber> {
+        pub vote: bool,
+        pub block: BlockNumber,
+    }
+
+    /// `VotesByAccount` in the layout of version 3.
+    #[storage_alias]
+    pub type VotesByAccount<T: Config<I>, I: 'static> = StorageDoubleMap<
+        Pallet<T, I>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        Blake2_128Concat,
+        ProposalId,
+        VoteRecord<BlockNumberFor<T>>,
+    >;
+
+    /// Marks existing proposals as regular ones. The reason closed ones were closed for was
+    /// not recorded, so it stays unknown.
+    pub struct UncheckedMigrateToV3<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV3<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated = 0u64;
+            Proposals::<T, I>::translate::<v2::ProposalInfo<T::AccountId, BlockNumberFor<T>>, _>(
+                |_, old| {
+                    translated.saturating_inc();
+                    Some(crate::ProposalInfo {
+                        proposer: old.proposer,
+                        description: old.description,
+                        start_block: old.start_block,
+                        end_block: old.end_block,
+                        is_closed: old.is_closed,
+                        extensions: old.extensions,
+                        track: old.track,
+                        approved: old.approved,
+                        emergency: false,
+                        close_reason: None,
+                    })
+                },
+            );
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok(proposal_count::<T, I>())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+            frame_support::ensure!(
+                Proposals::<T, I>::iter_values().count() as u32 == count,
+                "proposals were lost migrating to v3"
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrates from version 2 to 3.
+    pub type MigrateToV3<T, I = ()> = VersionedMigration<
+        2,
+        3,
+        UncheckedMigrateToV3<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Version 4 stores a `VoteRecord` with every vote in `Votes` and `VotesByAccount`.
+pub mod v4 {
+    use super::*;
+
+    /// Turns the votes of `Votes` into records, and rewrites `VotesByAccount` with the same
+    /// records. The block a vote was cast in is kept where `VotesByAccount` has it, and is the
+    /// block of the upgrade otherwise. The balance is the voter's current free balance, and no
+    /// conviction or reason is recorded.
+    ///
+    /// `ParticipationOf` is rebuilt from the migrated votes, so votes pruned before the upgrade
+    /// are not counted.
+    pub struct UncheckedMigrateToV4<T, I = ()>(PhantomData<(T, I)>);
+
+    impl<T: Config<I>, I: 'static> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV4<T, I> {
+        fn on_runtime_upgrade() -> Weight {
+            let now = frame_system::Pallet::<T>::block_number();
+            let record = |who: &T::AccountId, vote, block| VoteRecord {
+                vote,
+                block,
+                balance: T::Currency::free_balance(who),
+                conviction: Conviction::None,
+                reason: None,
+            };
+            let (mut reads, mut writes) = (1u64, 0u64);
+
+            let cleared = ParticipationOf::<T, I>::clear(u32::MAX, None);
+            reads.saturating_accrue(cleared.loops.into());
+            writes.saturating_accrue(cleared.unique.into());
+
+            VotesByAccount::<T, I>::translate::<v3::VoteRecord<BlockNumberFor<T>>, _>(
+                |who, _, old| {
+                    reads.saturating_accrue(2);
+                    writes.saturating_inc();
+                    Some(record(&who, old.vote, old.block))
+                },
+            );
+            Votes::<T, I>::translate::<bool, _>(|proposal_id, who, vote| {
+                reads.saturating_accrue(3);
+                writes.saturating_accrue(2);
+                ParticipationOf::<T, I>::mutate(&who, |participation| {
+                    if vote {
+                        participation.for_votes.saturating_inc();
+                    } else {
+                        participation.against_votes.saturating_inc();
+                    }
+                });
+                let migrated = VotesByAccount::<T, I>::get(&who, proposal_id).unwrap_or_else(|| {
+                    // The vote predates the index.
+                    reads.saturating_inc();
+                    writes.saturating_inc();
+                    let migrated = record(&who, vote, now);
+                    VotesByAccount::<T, I>::insert(&who, proposal_id, &migrated);
+                    migrated
+                });
+                Some(migrated)
+            });
+            T::DbWeight::get().reads_writes(reads, writes)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            Ok((Votes::<T, I>::iter_keys().count() as u32).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let count = u32::decode(&mut &state[..]).map_err(|_| "invalid pre-upgrade state")?;
+            let mut migrated = 0u32;
+            for (proposal_id, who, record) in Votes::<T, I>::iter() {
+                frame_support::ensure!(
+                    VotesByAccount::<T, I>::get(&who, proposal_id) == Some(record),
+                    "a vote is missing from VotesByAccount after migrating to v4"
+                );
+                migrated.saturating_inc();
+            }
+            frame_support::ensure!(migrated == count, "votes were lost migrating to v4");
+
+            let mut participation = BTreeMap::<T::AccountId, VoteTally>::new();
+            for (_, who, record) in Votes::<T, I>::iter() {
+                let tally = participation.entry(who).or_default();
+                if record.vote {
+                    tally.for_votes.saturating_inc();
+                } else {
+                    tally.against_votes.saturating_inc();
+                }
+            }
+            frame_support::ensure!(
+                ParticipationOf::<T, I>::iter().collect::<BTreeMap<_, _>>() == participation,
+                "ParticipationOf does not match the votes after migrating to v4"
+            );
+            Ok(())
+        }
+    }
+
+    /// Migrates from version 3 to 4.
+    pub type MigrateToV4<T, I = ()> = VersionedMigration<
+        3,
+        4,
+        UncheckedMigrateToV4<T, I>,
+        Pallet<T, I>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -1,12 +1,25 @@
 //! Mock runtime for testing the simple governance pallet.
 
 use crate as pallet_simple_governance;
+use crate::{
+    CloseReason, Curve, EnsureTrack, ManageMembers, OnProposalStatusChange, ProposalId, SudoKey,
+    TrackId, TrackInfo, TracksInfo,
+};
 use frame_support::{
-    derive_impl, parameter_types,
-    traits::{OnFinalize, OnInitialize},
+    derive_impl, instances::Instance1, ord_parameter_types, parameter_types,
+    traits::{Contains, OnFinalize, OnInitialize, WithdrawReasons},
+    weights::Weight,
+};
+use frame_system::{
+    offchain::{
+        AppCrypto, CreateBare, CreateSignedTransaction, CreateTransactionBase, SigningTypes,
+    },
+    EnsureRoot, EnsureSigned, EnsureSignedBy,
 };
 use sp_runtime::{
-    traits::IdentityLookup, BuildStorage,
+    testing::{TestSignature, TestXt, UintAuthorityId},
+    traits::{Convert, ConvertInto, IdentityLookup},
+    BuildStorage, Perbill,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -34,6 +47,15 @@ mod test_runtime {
 
     #[runtime::pallet_index(1)]
     pub type SimpleGovernance = pallet_simple_governance::Pallet<Test>;
+
+    #[runtime::pallet_index(2)]
+    pub type Balances = pallet_balances::Pallet<Test>;
+
+    #[runtime::pallet_index(3)]
+    pub type Vesting = pallet_vesting::Pallet<Test>;
+
+    #[runtime::pallet_index(4)]
+    pub type TechnicalGovernance = pallet_simple_governance::Pallet<Test, Instance1>;
 }
 
 parameter_types! {
@@ -46,12 +68,274 @@ impl frame_system::Config for Test {
     type Block = Block;
     type AccountId = u64;
     type Lookup = IdentityLookup<Self::AccountId>;
+    type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+    type AccountStore = System;
+}
+
+parameter_types! {
+    pub const MinVestedTransfer: u64 = 1;
+    pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+        WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BlockNumberToBalance = ConvertInto;
+    type MinVestedTransfer = MinVestedTransfer;
+    type WeightInfo = ();
+    type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+    type BlockNumberProvider = System;
+    const MAX_VESTING_SCHEDULES: u32 = 3;
 }
 
 parameter_types! {
     pub const MaxDescriptionLength: u32 = 256;
     pub const DefaultVotingPeriod: u64 = 100;
-    pub const MaxProposalsPerBlock: u32 = 10;
+    pub static MaxProposalsPerBlock: u32 = 8;
+    pub const MaxActiveProposalsPerAccount: u32 = 5;
+    pub const MaxActiveProposals: u32 = 8;
+    pub static ProposalCooldown: u64 = 0;
+    pub static MaxFreeVotesPerBlock: u32 = 2;
+    pub const MaxBatchSize: u32 = 4;
+    pub static EligibleElectorate: u32 = 0;
+    pub static EarlyDecisionThreshold: Option<Perbill> = None;
+    pub static EarlyApproval: bool = true;
+    pub static ConfirmationWindow: u64 = 0;
+    pub static DeadlineExtension: u64 = 0;
+    pub static MaxDeadlineExtensions: u32 = 0;
+    pub static MaxVotesRemovedPerTask: u32 = 100;
+    pub const UnsignedPriority: u64 = 1 << 20;
+    pub const UnsignedLongevity: u64 = 5;
+    pub const TreasuryAccount: u64 = 1000;
+    pub const MinFastTrackPeriod: u64 = 10;
+    pub static SudoKeySet: bool = true;
+    pub static CommitteeMembers: Vec<u64> = vec![];
+    pub static ValidatorSet: Vec<u64> = vec![1, 2];
+    pub static Offenders: Vec<u64> = vec![];
+    pub const MinVotingPeriod: u64 = 10;
+    pub const MaxVotingPeriod: u64 = 1000;
+    pub static HookCalls: Vec<HookCall> = vec![];
+    pub const HookWeight: Weight = Weight::from_parts(1_000_000, 0);
+    pub static MaxCallWeight: Weight = Weight::from_parts(1_000_000_000, 10_000);
+}
+
+ord_parameter_types! {
+    pub const TechnicalCommittee: u64 = 100;
+}
+
+/// Spends of up to 1000 on the general track, none on the fast track.
+pub struct TestSpendLimit;
+impl Convert<TrackId, u64> for TestSpendLimit {
+    fn convert(track: TrackId) -> u64 {
+        match track {
+            0 => 1000,
+            _ => 0,
+        }
+    }
+}
+
+/// A sudo key tracked in `SudoKeySet`.
+pub struct TestSudoKey;
+impl SudoKey for TestSudoKey {
+    fn exists() -> bool {
+        SudoKeySet::get()
+    }
+
+    fn remove() -> sp_runtime::DispatchResult {
+        SudoKeySet::set(false);
+        Ok(())
+    }
+}
+
+/// A committee whose members are kept in `CommitteeMembers`.
+pub struct TestCommittee;
+impl ManageMembers<u64> for TestCommittee {
+    fn add_member(who: &u64) -> sp_runtime::DispatchResult {
+        let mut members = CommitteeMembers::get();
+        if members.contains(who) {
+            return Err(sp_runtime::DispatchError::Other("already a member"));
+        }
+        members.push(*who);
+        CommitteeMembers::set(members);
+        Ok(())
+    }
+
+    fn remove_member(who: &u64) -> sp_runtime::DispatchResult {
+        let mut members = CommitteeMembers::get();
+        if !members.contains(who) {
+            return Err(sp_runtime::DispatchError::Other("not a member"));
+        }
+        members.retain(|member| member != who);
+        CommitteeMembers::set(members);
+        Ok(())
+    }
+}
+
+/// A validator set kept in `ValidatorSet`, with at least two validators.
+pub struct TestValidators;
+impl ManageMembers<u64> for TestValidators {
+    fn add_member(who: &u64) -> sp_runtime::DispatchResult {
+        let mut validators = ValidatorSet::get();
+        if validators.contains(who) {
+            return Err(sp_runtime::DispatchError::Other("already a validator"));
+        }
+        validators.push(*who);
+        ValidatorSet::set(validators);
+        Ok(())
+    }
+
+    fn remove_member(who: &u64) -> sp_runtime::DispatchResult {
+        let mut validators = ValidatorSet::get();
+        if validators.len() <= 2 {
+            return Err(sp_runtime::DispatchError::Other("too few validators"));
+        }
+        validators.retain(|validator| validator != who);
+        ValidatorSet::set(validators);
+        Ok(())
+    }
+}
+
+/// Validators listed in `Offenders` were reported for an offence.
+pub struct TestOffenders;
+impl Contains<u64> for TestOffenders {
+    fn contains(who: &u64) -> bool {
+        Offenders::get().contains(who)
+    }
+}
+
+/// A call of the governance hooks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HookCall {
+    Created(ProposalId, u64),
+    Voted(ProposalId, u64, bool),
+    Closed(ProposalId, bool, CloseReason),
+}
+
+/// Records every call in `HookCalls` and uses `HookWeight` for it.
+pub struct RecordHooks;
+impl OnProposalStatusChange<u64> for RecordHooks {
+    fn on_created(proposal_id: ProposalId, proposer: &u64) -> Weight {
+        HookCalls::mutate(|calls| calls.push(HookCall::Created(proposal_id, *proposer)));
+        HookWeight::get()
+    }
+
+    fn on_voted(proposal_id: ProposalId, voter: &u64, aye: bool) -> Weight {
+        HookCalls::mutate(|calls| calls.push(HookCall::Voted(proposal_id, *voter, aye)));
+        HookWeight::get()
+    }
+
+    fn on_closed(proposal_id: ProposalId, approved: bool, reason: CloseReason) -> Weight {
+        HookCalls::mutate(|calls| calls.push(HookCall::Closed(proposal_id, approved, reason)));
+        HookWeight::get()
+    }
+
+    fn max_weight() -> Weight {
+        HookWeight::get()
+    }
+}
+
+pub struct TestTracks;
+impl TracksInfo for TestTracks {
+    fn tracks() -> &'static [(TrackId, TrackInfo)] {
+        static TRACKS: [(TrackId, TrackInfo); 2] = [
+            (
+                0,
+                TrackInfo {
+                    name: "general",
+                    min_approval: Curve::LinearDecreasing {
+                        ceil: Perbill::from_percent(100),
+                        floor: Perbill::from_percent(60),
+                    },
+                    min_support: Curve::LinearDecreasing {
+                        ceil: Perbill::from_percent(50),
+                        floor: Perbill::from_percent(0),
+                    },
+                },
+            ),
+            (
+                1,
+                TrackInfo {
+                    name: "fast",
+                    min_approval: Curve::Reciprocal {
+                        ceil: Perbill::from_percent(100),
+                        floor: Perbill::from_percent(50),
+                        factor: 9,
+                    },
+                    min_support: Curve::Reciprocal {
+                        ceil: Perbill::from_percent(50),
+                        floor: Perbill::from_percent(10),
+                        factor: 9,
+                    },
+                },
+            ),
+        ];
+        &TRACKS
+    }
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<LocalCall> CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> CreateBare<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_bare(call: RuntimeCall) -> Extrinsic {
+        Extrinsic::new_bare(call)
+    }
+}
+
+impl<LocalCall> CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: UintAuthorityId,
+        account: u64,
+        _nonce: u64,
+    ) -> Option<Extrinsic> {
+        Some(Extrinsic::new_signed(call, account, (), ()))
+    }
+}
+
+/// Signs off-chain worker transactions with the keys set through
+/// `UintAuthorityId::set_all_keys`.
+pub struct TestAuthId;
+impl AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+}
+
+/// Signs ballots with `TestSignature`, which only checks the signer and the message.
+pub struct TestBallotSigner;
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u64, TestSignature> for TestBallotSigner {
+    fn voter() -> u64 {
+        42
+    }
+
+    fn sign(who: &u64, message: &[u8]) -> TestSignature {
+        TestSignature(*who, message.to_vec())
+    }
 }
 
 impl pallet_simple_governance::Config for Test {
@@ -60,16 +344,121 @@ impl pallet_simple_governance::Config for Test {
     type MaxDescriptionLength = MaxDescriptionLength;
     type DefaultVotingPeriod = DefaultVotingPeriod;
     type MaxProposalsPerBlock = MaxProposalsPerBlock;
+    type MaxActiveProposalsPerAccount = MaxActiveProposalsPerAccount;
+    type MaxActiveProposals = MaxActiveProposals;
+    type ProposalCooldown = ProposalCooldown;
+    type EligibleElectorate = EligibleElectorate;
+    type EarlyDecisionThreshold = EarlyDecisionThreshold;
+    type EarlyApproval = EarlyApproval;
+    type ConfirmationWindow = ConfirmationWindow;
+    type DeadlineExtension = DeadlineExtension;
+    type MaxDeadlineExtensions = MaxDeadlineExtensions;
+    type Tracks = TestTracks;
+    type MaxVotesRemovedPerTask = MaxVotesRemovedPerTask;
+    type AuthorityId = TestAuthId;
+    type UnsignedPriority = UnsignedPriority;
+    type UnsignedLongevity = UnsignedLongevity;
+    type Currency = Balances;
+    type Vesting = Vesting;
+    type TreasuryAccount = TreasuryAccount;
+    type SpendLimit = TestSpendLimit;
+    type FastTrackOrigin = EnsureSignedBy<TechnicalCommittee, u64>;
+    type InstantOrigin = EnsureRoot<u64>;
+    type MinFastTrackPeriod = MinFastTrackPeriod;
+    type SudoKey = TestSudoKey;
+    type ProposeOrigin = EnsureSigned<u64>;
+    type ActionProposeOrigin = EnsureSigned<u64>;
+    type CancelOrigin = EnsureSignedBy<TechnicalCommittee, u64>;
+    type Committee = TestCommittee;
+    type Validators = TestValidators;
+    type Offenders = TestOffenders;
+    type MinVotingPeriod = MinVotingPeriod;
+    type MaxVotingPeriod = MaxVotingPeriod;
+    type ParametersOrigin = EnsureRoot<u64>;
+    type GovernanceHooks = (RecordHooks, ());
+    type RuntimeOrigin = RuntimeOrigin;
+    type ProposalCall = RuntimeCall;
+    type MaxCallWeight = MaxCallWeight;
+    type MaxFreeVotesPerBlock = MaxFreeVotesPerBlock;
+    type MaxBatchSize = MaxBatchSize;
+    type BallotSignature = TestSignature;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TestBallotSigner;
+}
+
+parameter_types! {
+    pub const TechnicalVotingPeriod: u64 = 20;
+    pub const TechnicalMaxActiveProposals: u32 = 2;
+    pub const TechnicalMaxProposalsPerBlock: u32 = 2;
+    pub const TechnicalMaxBatchSize: u32 = 2;
+}
+
+/// A second, independent governance body with a shorter voting period and fewer open
+/// proposals. Its parameters are set by proposals passed on the first body's fast track.
+impl pallet_simple_governance::Config<Instance1> for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxDescriptionLength = MaxDescriptionLength;
+    type DefaultVotingPeriod = TechnicalVotingPeriod;
+    type MaxProposalsPerBlock = TechnicalMaxProposalsPerBlock;
+    type MaxActiveProposalsPerAccount = MaxActiveProposalsPerAccount;
+    type MaxActiveProposals = TechnicalMaxActiveProposals;
+    type ProposalCooldown = ProposalCooldown;
+    type EligibleElectorate = EligibleElectorate;
+    type EarlyDecisionThreshold = EarlyDecisionThreshold;
+    type EarlyApproval = EarlyApproval;
+    type ConfirmationWindow = ConfirmationWindow;
+    type DeadlineExtension = DeadlineExtension;
+    type MaxDeadlineExtensions = MaxDeadlineExtensions;
+    type Tracks = TestTracks;
+    type MaxVotesRemovedPerTask = MaxVotesRemovedPerTask;
+    type AuthorityId = TestAuthId;
+    type UnsignedPriority = UnsignedPriority;
+    type UnsignedLongevity = UnsignedLongevity;
+    type Currency = Balances;
+    type Vesting = Vesting;
+    type TreasuryAccount = TreasuryAccount;
+    type SpendLimit = TestSpendLimit;
+    type FastTrackOrigin = EnsureSignedBy<TechnicalCommittee, u64>;
+    type InstantOrigin = EnsureRoot<u64>;
+    type MinFastTrackPeriod = MinFastTrackPeriod;
+    type SudoKey = TestSudoKey;
+    type ProposeOrigin = EnsureSigned<u64>;
+    type ActionProposeOrigin = EnsureSignedBy<TechnicalCommittee, u64>;
+    type CancelOrigin = EnsureSignedBy<TechnicalCommittee, u64>;
+    type Committee = TestCommittee;
+    type Validators = TestValidators;
+    type Offenders = TestOffenders;
+    type MinVotingPeriod = MinVotingPeriod;
+    type MaxVotingPeriod = MaxVotingPeriod;
+    type ParametersOrigin = EnsureTrack<1>;
+    type GovernanceHooks = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type ProposalCall = RuntimeCall;
+    type MaxCallWeight = MaxCallWeight;
+    type MaxFreeVotesPerBlock = MaxFreeVotesPerBlock;
+    type MaxBatchSize = TechnicalMaxBatchSize;
+    type BallotSignature = TestSignature;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TestBallotSigner;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
     
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(TreasuryAccount::get(), 10_000)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    
     crate::GenesisConfig::<Test> {
         proposals: vec![
             // Add some initial proposals for testing if needed
         ],
+        ..Default::default()
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -85,5 +474,6 @@ pub fn run_to_block(n: u64) {
         System::set_block_number(System::block_number() + 1);
         <System as OnInitialize<u64>>::on_initialize(System::block_number());
         <SimpleGovernance as OnInitialize<u64>>::on_initialize(System::block_number());
+        <TechnicalGovernance as OnInitialize<u64>>::on_initialize(System::block_number());
     }
 }
\ No newline at end of file
@@ -0,0 +1,196 @@
+//! Off-chain worker support.
+//!
+//! After every imported block the worker submits `close_proposal` transactions for open
+//! proposals whose voting period has ended, so the backlog does not depend on spare block
+//! weight alone. The transactions are signed with a local `govn` key if the node has one and
+//! unsigned otherwise, in which case they are checked by the pallet's `ValidateUnsigned`.
+//!
+//! If an HTTP endpoint is set in persistent off-chain local storage under
+//! [`RESULTS_ENDPOINT_KEY`], the worker also POSTs the result of every proposal closed in the
+//! block as JSON, e.g.
+//!
+//! ```json
+//! {"pallet":"SimpleGovernance","proposal_id":0,"for_votes":3,"against_votes":1,"approved":true,"reason":"expired","block":102}
+//! ```
+//!
+//! All instances of the pallet share the endpoint. `pallet` is the name of the instance in the
+//! runtime, since proposal IDs are only unique within an instance.
+
+use crate::pallet::*;
+use alloc::{format, string::String, vec, vec::Vec};
+use codec::Encode;
+use frame_support::traits::{IsType, PalletInfoAccess};
+use frame_system::{
+    offchain::{CreateBare, SendSignedTransaction, Signer, SubmitTransaction},
+    pallet_prelude::BlockNumberFor,
+};
+use sp_runtime::{
+    offchain::{http, storage::StorageValueRef, Duration, StorageKind},
+    traits::{Saturating, UniqueSaturatedInto},
+    KeyTypeId,
+};
+
+const LOG_TARGET: &str = "runtime::simple-governance";
+
+/// Key type of the keys the off-chain worker signs closure transactions with.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"govn");
+
+/// Persistent off-chain local storage key holding the URL closed-proposal results are POSTed
+/// to. The value is the raw URL, e.g. set through the `offchain_localStorageSet` RPC.
+pub const RESULTS_ENDPOINT_KEY: &[u8] = b"simple-governance::results-endpoint";
+
+/// Prefix of the persistent off-chain local storage keys recording when a closure transaction
+/// was last submitted for a proposal, followed by the pallet instance's index.
+const CLOSURE_SENT_PREFIX: &[u8] = b"simple-governance::closure-sent";
+
+/// How long the worker waits for the results endpoint to respond.
+const HTTP_TIMEOUT_MS: u64 = 2_000;
+
+/// Crypto used by the off-chain worker to sign closure transactions.
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// Signing identity of the off-chain worker in runtimes using `MultiSignature`.
+    pub struct GovernanceAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for GovernanceAuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+    /// Submit a `close_proposal` transaction for every open proposal that expired by `now`,
+    /// skipping proposals a transaction was already submitted for within the unsigned
+    /// longevity.
+    pub(crate) fn offchain_submit_closures(now: BlockNumberFor<T>) {
+        let expired: Vec<ProposalId> = Self::active_proposals()
+            .into_iter()
+            .filter(|proposal_id| {
+                Self::proposals(proposal_id)
+                    .is_some_and(|proposal| !proposal.is_closed && now > proposal.end_block)
+            })
+            .collect();
+        if expired.is_empty() {
+            return;
+        }
+
+        let signer = Signer::<T, T::AuthorityId>::any_account();
+        let resubmit_after: BlockNumberFor<T> =
+            T::UnsignedLongevity::get().unique_saturated_into();
+
+        for proposal_id in expired {
+            let key = Self::closure_sent_key(proposal_id);
+            let sent = StorageValueRef::persistent(&key);
+            if let Ok(Some(sent_at)) = sent.get::<BlockNumberFor<T>>() {
+                if now < sent_at.saturating_add(resubmit_after) {
+                    continue;
+                }
+            }
+
+            let call = Call::<T, I>::close_proposal { proposal_id };
+            let submitted = if signer.can_sign() {
+                matches!(signer.send_signed_transaction(|_| call.clone()), Some((_, Ok(()))))
+            } else {
+                let xt = <T as CreateBare<Call<T, I>>>::create_bare(call.into());
+                SubmitTransaction::<T, Call<T, I>>::submit_transaction(xt).is_ok()
+            };
+
+            if submitted {
+                sent.set(&now);
+            } else {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "failed to submit closure of proposal {proposal_id}",
+                );
+            }
+        }
+    }
+
+    /// POST the result of every proposal closed in the current block to the results endpoint,
+    /// if one is configured, and forget the closure transactions sent for them.
+    pub(crate) fn offchain_publish_results(now: BlockNumberFor<T>) {
+        let closed: Vec<_> = frame_system::Pallet::<T>::read_events_no_consensus()
+            .filter_map(|record| {
+                let event = <T as Config<I>>::RuntimeEvent::from_ref(&record.event).clone();
+                match event.try_into() {
+                    Ok(Event::<T, I>::ProposalClosed {
+                        proposal_id,
+                        for_votes,
+                        against_votes,
+                        reason,
+                        approved,
+                    }) => Some((proposal_id, for_votes, against_votes, reason, approved)),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        // Closed proposals need no further closure transactions
+        for (proposal_id, ..) in &closed {
+            StorageValueRef::persistent(&Self::closure_sent_key(*proposal_id)).clear();
+        }
+
+        let Some(endpoint) =
+            sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, RESULTS_ENDPOINT_KEY)
+        else {
+            return;
+        };
+        let Ok(endpoint) = String::from_utf8(endpoint) else {
+            log::warn!(target: LOG_TARGET, "results endpoint is not valid UTF-8");
+            return;
+        };
+
+        let pallet = Self::name();
+        let block: u64 = now.unique_saturated_into();
+        for (proposal_id, for_votes, against_votes, reason, approved) in closed {
+            let reason = match reason {
+                CloseReason::Expired => "expired",
+                CloseReason::EarlyDecision => "early_decision",
+                CloseReason::Instant => "instant",
+                CloseReason::Cancelled => "cancelled",
+            };
+            let body = format!(
+                "{{\"pallet\":\"{pallet}\",\"proposal_id\":{proposal_id},\
+                 \"for_votes\":{for_votes},\"against_votes\":{against_votes},\
+                 \"approved\":{approved},\"reason\":\"{reason}\",\"block\":{block}}}",
+            );
+
+            if let Err(e) = Self::post_json(&endpoint, body.into_bytes()) {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "failed to publish result of proposal {proposal_id}: {e:?}",
+                );
+            }
+        }
+    }
+
+    /// The off-chain local storage key recording when a closure of `proposal_id` was submitted.
+    fn closure_sent_key(proposal_id: ProposalId) -> Vec<u8> {
+        (CLOSURE_SENT_PREFIX, Self::index() as u32, proposal_id).encode()
+    }
+
+    /// POST `body` as JSON to `url` and wait for a successful response.
+    fn post_json(url: &str, body: Vec<u8>) -> Result<(), http::Error> {
+        let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(HTTP_TIMEOUT_MS));
+        let pending = http::Request::post(url, vec![body])
+            .add_header("Content-Type", "application/json")
+            .deadline(deadline)
+            .send()
+            .map_err(|_| http::Error::IoError)?;
+
+        let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+        if response.code / 100 != 2 {
+            return Err(http::Error::Unknown);
+        }
+
+        Ok(())
+    }
+}
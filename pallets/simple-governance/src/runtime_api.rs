@@ -0,0 +1,40 @@
+//! Runtime API for inspecting governance proposals.
+
+use crate::{DryRunEffects, DryRunError, ParticipationStats, ProposalId, VoteRecord};
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+    /// Inspect proposals without changing state.
+    pub trait GovernanceApi<RuntimeEvent, Hash>
+    where
+        RuntimeEvent: Codec,
+        Hash: Codec,
+    {
+        /// Dispatch the call of proposal `proposal_id` with the governance origin and roll back
+        /// its effects, returning them.
+        fn dry_run(
+            proposal_id: ProposalId,
+        ) -> Result<DryRunEffects<RuntimeEvent, Hash>, DryRunError>;
+    }
+
+    /// Look up the votes of an account.
+    pub trait GovernanceVotesApi<AccountId, Balance, BlockNumber, Hash>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+    {
+        /// Up to `limit` votes of `who`. Pass the last proposal ID of a page as `start_after`
+        /// to get the next one.
+        fn votes_of(
+            who: AccountId,
+            start_after: Option<ProposalId>,
+            limit: u32,
+        ) -> Vec<(ProposalId, VoteRecord<Balance, BlockNumber, Hash>)>;
+
+        /// How many votes `who` has cast and which share of all proposals they voted on.
+        fn participation(who: AccountId) -> ParticipationStats;
+    }
+}
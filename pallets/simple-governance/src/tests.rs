@@ -1,11 +1,60 @@
 //! Unit tests for the simple governance pallet.
 
-use crate::{mock::*, Error, Event};
+use crate::{
+    mock::*, offchain::RESULTS_ENDPOINT_KEY, Ballot, CloseReason, Conviction, Curve, DryRunError,
+    EnsureApproved, EnsureTrack, Error, Event, GovernanceOrigin, GovernanceParameters,
+    MembershipChange, OnProposalStatusChange, ParticipationStats, ProposalAction, SpendVesting,
+    Task as GovernanceTask, VoteReason, VoteRecord, VoteTally, WeightInfo,
+};
+use codec::{Decode, Encode};
 use frame_support::{
     assert_noop, assert_ok,
+    dispatch::{CheckIfFeeless, DispatchClass, GetDispatchInfo},
+    instances::Instance1,
+    pallet_prelude::{InvalidTransaction, TransactionSource, ValidateUnsigned},
+    traits::{
+        Currency, EnsureOrigin, IntegrityTest, OffchainWorker, OnIdle, OnInitialize,
+        OnRuntimeUpgrade, StorageVersion, Task,
+    },
+    weights::Weight,
     BoundedVec,
 };
-use sp_runtime::BuildStorage;
+use sp_core::{
+    offchain::{
+        testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt,
+    },
+    traits::ReadRuntimeVersionExt,
+    H256,
+};
+use sp_runtime::{
+    generic::Preamble,
+    testing::{TestSignature, UintAuthorityId},
+    traits::Hash,
+    BuildStorage, DispatchError, Perbill,
+};
+use sp_version::RuntimeVersion;
+
+/// Reads the same runtime version from any code.
+struct ReadRuntimeVersion(Vec<u8>);
+
+impl sp_core::traits::ReadRuntimeVersion for ReadRuntimeVersion {
+    fn read_runtime_version(
+        &self,
+        _wasm_code: &[u8],
+        _ext: &mut dyn sp_externalities::Externalities,
+    ) -> Result<Vec<u8>, String> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Test externalities in which any code reports `spec_version`.
+fn new_upgrade_test_ext(spec_version: u32) -> sp_io::TestExternalities {
+    let version = RuntimeVersion { spec_version, ..Default::default() };
+    let mut ext = new_test_ext();
+    ext.register_extension(ReadRuntimeVersionExt::new(ReadRuntimeVersion(version.encode())));
+    ext
+}
 
 #[test]
 fn propose_works() {
@@ -27,6 +76,7 @@ fn propose_works() {
         assert_eq!(proposal.description.into_inner(), description);
         assert_eq!(proposal.start_block, 1);
         assert_eq!(proposal.end_block, 101); // 1 + DefaultVotingPeriod (100)
+        assert_eq!(proposal.track, 0);
         assert!(!proposal.is_closed);
         
         // Check that vote tally was initialized
@@ -44,6 +94,7 @@ fn propose_works() {
                 proposer,
                 description: BoundedVec::try_from(description).unwrap(),
                 end_block: 101,
+                track: 0,
             }.into()
         );
     });
@@ -61,6 +112,120 @@ fn propose_fails_with_long_description() {
     });
 }
 
+#[test]
+fn propose_fails_when_account_limit_reached() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        // Account 1 opens the maximum number of proposals (MaxActiveProposalsPerAccount = 5)
+        for _ in 0..5 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(1),
+                b"Test proposal".to_vec()
+            ));
+        }
+        assert_eq!(SimpleGovernance::active_proposals_of(1), 5);
+        
+        assert_noop!(
+            SimpleGovernance::propose(RuntimeOrigin::signed(1), b"One too many".to_vec()),
+            Error::<Test>::TooManyActiveProposalsForAccount
+        );
+        
+        // Other accounts are unaffected
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(2),
+            b"Test proposal".to_vec()
+        ));
+    });
+}
+
+#[test]
+fn propose_fails_when_global_limit_reached() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        // Open the maximum number of proposals across accounts (MaxActiveProposals = 8)
+        for proposer in 1..=8u64 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(proposer),
+                b"Test proposal".to_vec()
+            ));
+        }
+        assert_eq!(SimpleGovernance::active_proposals().len(), 8);
+        
+        assert_noop!(
+            SimpleGovernance::propose(RuntimeOrigin::signed(9), b"One too many".to_vec()),
+            Error::<Test>::TooManyActiveProposals
+        );
+    });
+}
+
+#[test]
+fn propose_fails_during_cooldown() {
+    new_test_ext().execute_with(|| {
+        ProposalCooldown::set(10);
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        assert_eq!(SimpleGovernance::last_proposal_block(1), Some(1));
+        
+        // Still within the cooldown (ends at block 11)
+        System::set_block_number(10);
+        assert_noop!(
+            SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Too soon".to_vec()),
+            Error::<Test>::ProposalCooldownActive
+        );
+        
+        // Other accounts are unaffected
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(2),
+            b"Test proposal".to_vec()
+        ));
+        
+        // Cooldown has passed
+        System::set_block_number(11);
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+    });
+}
+
+#[test]
+fn closing_proposal_releases_active_slot() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        for _ in 0..5 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(1),
+                b"Test proposal".to_vec()
+            ));
+        }
+        
+        // Move past voting period manually and close one proposal
+        System::set_block_number(102);
+        assert_ok!(SimpleGovernance::close_proposal(RuntimeOrigin::signed(2), 0));
+        
+        assert_eq!(SimpleGovernance::active_proposals_of(1), 4);
+        assert_eq!(SimpleGovernance::active_proposals().into_inner(), vec![1, 2, 3, 4]);
+        
+        // The freed slot can be used again
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        
+        // Auto-closing releases the remaining slots
+        run_to_block(203);
+        assert_eq!(SimpleGovernance::active_proposals_of(1), 0);
+        assert!(SimpleGovernance::active_proposals().is_empty());
+    });
+}
+
 #[test]
 fn vote_works() {
     new_test_ext().execute_with(|| {
@@ -76,7 +241,7 @@ fn vote_works() {
         assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
         
         // Check that the vote was recorded
-        assert_eq!(SimpleGovernance::votes(0, 2), Some(true));
+        assert!(SimpleGovernance::votes(0, 2).unwrap().vote);
         
         // Check that vote tally was updated
         let tally = SimpleGovernance::vote_tallies(0).unwrap();
@@ -97,6 +262,10 @@ fn vote_works() {
                 proposal_id: 0,
                 voter: 2,
                 vote: true,
+                block: 1,
+                balance: 0,
+                conviction: Conviction::None,
+                reason: None,
             }.into()
         );
         
@@ -105,6 +274,10 @@ fn vote_works() {
                 proposal_id: 0,
                 voter: 3,
                 vote: false,
+                block: 1,
+                balance: 0,
+                conviction: Conviction::None,
+                reason: None,
             }.into()
         );
     });
@@ -218,6 +391,8 @@ fn close_proposal_works() {
                 proposal_id: 0,
                 for_votes: 2,
                 against_votes: 1,
+                reason: CloseReason::Expired,
+                approved: true,
             }.into()
         );
     });
@@ -309,6 +484,8 @@ fn auto_close_on_initialize_works() {
                 proposal_id: 0,
                 for_votes: 1,
                 against_votes: 0,
+                reason: CloseReason::Expired,
+                approved: true,
             }.into()
         );
         
@@ -317,58 +494,2594 @@ fn auto_close_on_initialize_works() {
                 proposal_id: 1,
                 for_votes: 0,
                 against_votes: 1,
+                reason: CloseReason::Expired,
+                approved: false,
             }.into()
         );
     });
 }
 
 #[test]
-fn multiple_proposals_work() {
+fn early_close_when_outcome_cannot_change() {
     new_test_ext().execute_with(|| {
+        EligibleElectorate::set(5);
+        EarlyDecisionThreshold::set(Some(Perbill::one()));
         System::set_block_number(1);
         
-        // Create multiple proposals
-        for i in 0..5 {
-            assert_ok!(SimpleGovernance::propose(
-                RuntimeOrigin::signed(1),
-                format!("Proposal {}", i).as_bytes().to_vec()
-            ));
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        
+        // Two of five votes in favour do not decide the proposal yet
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, true));
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+        
+        // A third vote in favour cannot be overturned by the two remaining voters
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(4), 0, true));
+        assert!(SimpleGovernance::proposals(0).unwrap().is_closed);
+        assert!(SimpleGovernance::active_proposals().is_empty());
+        
+        System::assert_has_event(
+            Event::ProposalClosed {
+                proposal_id: 0,
+                for_votes: 3,
+                against_votes: 0,
+                reason: CloseReason::EarlyDecision,
+                approved: true,
+            }.into()
+        );
+        
+        // Further votes are rejected
+        assert_noop!(
+            SimpleGovernance::vote(RuntimeOrigin::signed(5), 0, false),
+            Error::<Test>::ProposalClosed
+        );
+    });
+}
+
+#[test]
+fn early_close_follows_the_track_curves() {
+    new_test_ext().execute_with(|| {
+        EligibleElectorate::set(4);
+        EarlyDecisionThreshold::set(Some(Perbill::one()));
+        System::set_block_number(1);
+
+        assert_ok!(SimpleGovernance::propose_on_track(
+            RuntimeOrigin::signed(1),
+            1,
+            b"Fast proposal".to_vec()
+        ));
+
+        // One in favour and two against could still end in a tie, which passes the fast
+        // track's 50% approval floor
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, false));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(4), 0, false));
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(5), 0, true));
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(proposal.approved);
+    });
+}
+
+#[test]
+fn early_close_waits_while_approval_could_fall_below_the_floor() {
+    new_test_ext().execute_with(|| {
+        EligibleElectorate::set(7);
+        EarlyDecisionThreshold::set(Some(Perbill::one()));
+        System::set_block_number(1);
+
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+
+        // A strict majority of the electorate is in favour, but three votes against would
+        // leave approval at 4/7, below the general track's 60% floor
+        for voter in 2..6 {
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(voter), 0, true));
         }
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+
+        // Five in favour pass whatever the last two voters do
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(6), 0, true));
+        assert!(SimpleGovernance::proposals(0).unwrap().is_closed);
+    });
+}
+
+#[test]
+fn early_close_on_supermajority() {
+    new_test_ext().execute_with(|| {
+        EligibleElectorate::set(10);
+        EarlyDecisionThreshold::set(Some(Perbill::from_percent(50)));
+        System::set_block_number(1);
         
-        // Check all proposals were created
-        for i in 0..5 {
-            assert!(SimpleGovernance::proposals(i).is_some());
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, false));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, false));
+        for voter in 4..8 {
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(voter), 0, true));
         }
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
         
-        assert_eq!(SimpleGovernance::next_proposal_id(), 5);
+        // Five of ten eligible voters in favour reaches the supermajority
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(8), 0, true));
+        assert!(SimpleGovernance::proposals(0).unwrap().is_closed);
+        
+        System::assert_has_event(
+            Event::ProposalClosed {
+                proposal_id: 0,
+                for_votes: 5,
+                against_votes: 2,
+                reason: CloseReason::EarlyDecision,
+                approved: true,
+            }.into()
+        );
     });
 }
 
 #[test]
-fn genesis_config_works() {
-    // Test that genesis config can initialize proposals
-    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+fn no_early_close_when_disabled() {
+    new_test_ext().execute_with(|| {
+        EligibleElectorate::set(3);
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        
+        for voter in 2..5 {
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(voter), 0, true));
+        }
+        
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+    });
+}
+
+#[test]
+fn late_flip_extends_voting_period() {
+    new_test_ext().execute_with(|| {
+        ConfirmationWindow::set(10);
+        DeadlineExtension::set(20);
+        MaxDeadlineExtensions::set(2);
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        
+        // Flipping the outcome well before the end block (101) does not extend
+        System::set_block_number(50);
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        assert_eq!(SimpleGovernance::proposals(0).unwrap().end_block, 101);
+        
+        // Flipping it inside the confirmation window does
+        System::set_block_number(95);
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, false));
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert_eq!(proposal.end_block, 121);
+        assert_eq!(proposal.extensions, 1);
+        System::assert_has_event(
+            Event::VotingPeriodExtended {
+                proposal_id: 0,
+                end_block: 121,
+                extensions: 1,
+            }.into()
+        );
+        
+        // Auto-closing respects the new end block
+        run_to_block(110);
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+        
+        // A vote that keeps the outcome does not extend
+        System::set_block_number(115);
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(4), 0, false));
+        assert_eq!(SimpleGovernance::proposals(0).unwrap().end_block, 121);
+        
+        // Second flip extends again
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(5), 0, true));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(6), 0, true));
+        assert_eq!(SimpleGovernance::proposals(0).unwrap().end_block, 141);
+        
+        // No further extensions once the maximum has been reached
+        System::set_block_number(135);
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(7), 0, false));
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert_eq!(proposal.end_block, 141);
+        assert_eq!(proposal.extensions, 2);
+        
+        assert_noop!(
+            SimpleGovernance::close_proposal(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::VotingPeriodNotEnded
+        );
+        run_to_block(142);
+        assert!(SimpleGovernance::proposals(0).unwrap().is_closed);
+    });
+}
+
+#[test]
+fn curves_fall_from_ceil_to_floor() {
+    let linear = Curve::LinearDecreasing {
+        ceil: Perbill::from_percent(100),
+        floor: Perbill::from_percent(60),
+    };
+    assert_eq!(linear.threshold(Perbill::zero()), Perbill::from_percent(100));
+    assert_eq!(linear.threshold(Perbill::from_percent(50)), Perbill::from_percent(80));
+    assert_eq!(linear.threshold(Perbill::one()), Perbill::from_percent(60));
     
-    crate::GenesisConfig::<Test> {
-        proposals: vec![
-            (1u64, b"Genesis proposal 1".to_vec()),
-            (2u64, b"Genesis proposal 2".to_vec()),
-        ],
-    }
-    .assimilate_storage(&mut t)
-    .unwrap();
+    let reciprocal = Curve::Reciprocal {
+        ceil: Perbill::from_percent(100),
+        floor: Perbill::from_percent(50),
+        factor: 9,
+    };
+    assert_eq!(reciprocal.threshold(Perbill::zero()), Perbill::from_percent(100));
+    assert_eq!(reciprocal.threshold(Perbill::one()), Perbill::from_percent(50));
     
-    let mut ext = sp_io::TestExternalities::from(t);
-    ext.execute_with(|| {
-        // Check proposals were created
-        assert_eq!(SimpleGovernance::next_proposal_id(), 2);
+    // Halfway through, the reciprocal curve is much closer to its floor than the linear one
+    let halfway = reciprocal.threshold(Perbill::from_percent(50));
+    assert!(halfway > Perbill::from_percent(54) && halfway < Perbill::from_percent(55));
+}
+
+#[test]
+fn propose_on_track_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
         
-        let proposal1 = SimpleGovernance::proposals(0).unwrap();
-        assert_eq!(proposal1.proposer, 1u64);
-        assert_eq!(proposal1.description.into_inner(), b"Genesis proposal 1".to_vec());
+        assert_ok!(SimpleGovernance::propose_on_track(
+            RuntimeOrigin::signed(1),
+            1,
+            b"Test proposal".to_vec()
+        ));
+        assert_eq!(SimpleGovernance::proposals(0).unwrap().track, 1);
         
-        let proposal2 = SimpleGovernance::proposals(1).unwrap();
-        assert_eq!(proposal2.proposer, 2u64);
-        assert_eq!(proposal2.description.into_inner(), b"Genesis proposal 2".to_vec());
+        assert_noop!(
+            SimpleGovernance::propose_on_track(RuntimeOrigin::signed(1), 7, b"Test".to_vec()),
+            Error::<Test>::UnknownTrack
+        );
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn uncontroversial_proposal_passes_early() {
+    new_test_ext().execute_with(|| {
+        EligibleElectorate::set(10);
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        
+        // 60% support and 100% approval clear both curves right away
+        for voter in 2..8 {
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(voter), 0, true));
+        }
+        
+        run_to_block(2);
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(proposal.approved);
+        
+        System::assert_has_event(
+            Event::ProposalClosed {
+                proposal_id: 0,
+                for_votes: 6,
+                against_votes: 0,
+                reason: CloseReason::EarlyDecision,
+                approved: true,
+            }.into()
+        );
+    });
+}
+
+#[test]
+fn no_early_approval_when_disabled() {
+    new_test_ext().execute_with(|| {
+        EligibleElectorate::set(10);
+        EarlyApproval::set(false);
+        System::set_block_number(1);
+
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        for voter in 2..8 {
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(voter), 0, true));
+        }
+
+        run_to_block(2);
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+
+        run_to_block(102);
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(proposal.approved);
+    });
+}
+
+#[test]
+fn action_proposals_are_not_approved_early() {
+    new_test_ext().execute_with(|| {
+        EligibleElectorate::set(10);
+        EarlyDecisionThreshold::set(Some(Perbill::from_percent(50)));
+        System::set_block_number(1);
+
+        assert_ok!(SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Fund the explorer".to_vec(),
+            7,
+            500,
+            None
+        ));
+
+        // Neither a supermajority of voters nor the curves pass it before its end block
+        for voter in 2..8 {
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(voter), 0, true));
+        }
+        run_to_block(2);
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+        assert_eq!(Balances::free_balance(7), 0);
+
+        run_to_block(102);
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.approved);
+        assert_eq!(proposal.close_reason, Some(CloseReason::Expired));
+        assert_eq!(Balances::free_balance(7), 500);
+    });
+}
+
+#[test]
+fn action_proposals_are_still_rejected_early() {
+    new_test_ext().execute_with(|| {
+        EligibleElectorate::set(10);
+        EarlyDecisionThreshold::set(Some(Perbill::from_percent(50)));
+        System::set_block_number(1);
+
+        assert_ok!(SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Fund the explorer".to_vec(),
+            7,
+            500,
+            None
+        ));
+        for voter in 2..7 {
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(voter), 0, false));
+        }
+
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(!proposal.approved);
+        assert_eq!(proposal.close_reason, Some(CloseReason::EarlyDecision));
+    });
+}
+
+#[test]
+fn contested_proposal_passes_once_curves_fall() {
+    new_test_ext().execute_with(|| {
+        EligibleElectorate::set(10);
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        
+        // 75% approval and 30% support
+        for voter in 2..5 {
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(voter), 0, true));
+        }
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(5), 0, false));
+        
+        // Required approval only falls to 75% after 62.5% of the voting period
+        run_to_block(63);
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+        
+        run_to_block(64);
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(proposal.approved);
+    });
+}
+
+#[test]
+fn proposal_below_curves_is_rejected_at_expiry() {
+    new_test_ext().execute_with(|| {
+        EligibleElectorate::set(10);
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        
+        // 50% approval never reaches the 60% floor
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, false));
+        
+        run_to_block(101);
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+        
+        run_to_block(102);
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(!proposal.approved);
+        
+        System::assert_has_event(
+            Event::ProposalClosed {
+                proposal_id: 0,
+                for_votes: 1,
+                against_votes: 1,
+                reason: CloseReason::Expired,
+                approved: false,
+            }.into()
+        );
+    });
+}
+
+#[test]
+fn on_initialize_resumes_from_cursor() {
+    new_test_ext().execute_with(|| {
+        MaxProposalsPerBlock::set(1);
+        System::set_block_number(1);
+        
+        for proposer in 1..=3u64 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(proposer),
+                b"Test proposal".to_vec()
+            ));
+        }
+        
+        // Each call evaluates a single proposal and moves the cursor on
+        System::set_block_number(102);
+        SimpleGovernance::on_initialize(102);
+        assert!(SimpleGovernance::proposals(0).unwrap().is_closed);
+        assert!(!SimpleGovernance::proposals(1).unwrap().is_closed);
+        assert_eq!(SimpleGovernance::process_cursor(), Some(0));
+        
+        SimpleGovernance::on_initialize(102);
+        assert!(SimpleGovernance::proposals(1).unwrap().is_closed);
+        assert!(!SimpleGovernance::proposals(2).unwrap().is_closed);
+        
+        SimpleGovernance::on_initialize(102);
+        assert!(SimpleGovernance::proposals(2).unwrap().is_closed);
+        assert!(SimpleGovernance::active_proposals().is_empty());
+    });
+}
+
+#[test]
+fn on_initialize_stays_within_its_share_of_the_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for proposer in 1..=3u64 {
+            let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+            assert_ok!(SimpleGovernance::propose_call(
+                RuntimeOrigin::signed(proposer),
+                0,
+                b"Test proposal".to_vec(),
+                Box::new(call)
+            ));
+            let proposal_id = SimpleGovernance::next_proposal_id() - 1;
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(5), proposal_id, true));
+        }
+
+        // Approved closures that may dispatch a call weighing 30% of the block do not fit in
+        // a quarter of it, but the first proposal is always evaluated
+        let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+        MaxCallWeight::set(Perbill::from_percent(30) * max_block);
+        System::set_block_number(102);
+        SimpleGovernance::on_initialize(102);
+        assert_eq!(SimpleGovernance::active_proposals().len(), 2);
+
+        // Weight the block already used counts against the share
+        MaxCallWeight::set(Weight::zero());
+        System::register_extra_weight_unchecked(max_block, DispatchClass::Mandatory);
+        SimpleGovernance::on_initialize(102);
+        assert_eq!(SimpleGovernance::active_proposals().len(), 1);
+
+        // The rest is left to `on_idle`
+        SimpleGovernance::on_idle(102, Weight::MAX);
+        assert!(SimpleGovernance::active_proposals().is_empty());
+    });
+}
+
+#[test]
+fn on_initialize_only_reserves_call_weight_for_dispatching_closures() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for proposer in 1..=3u64 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(proposer),
+                b"Test proposal".to_vec()
+            ));
+        }
+
+        // None of the closures dispatch a call, so the call weight is never reserved
+        let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+        MaxCallWeight::set(Perbill::from_percent(30) * max_block);
+        System::set_block_number(102);
+        let used = SimpleGovernance::on_initialize(102);
+        assert!(used.all_lte(crate::MAX_INITIALIZE_SHARE * max_block));
+        assert!(SimpleGovernance::active_proposals().is_empty());
+    });
+}
+
+#[test]
+fn on_initialize_leaves_open_proposals_it_cannot_close_for_later() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Text".to_vec()));
+        assert_ok!(SimpleGovernance::propose_call(
+            RuntimeOrigin::signed(2),
+            0,
+            b"Call".to_vec(),
+            Box::new(call)
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 1, true));
+
+        let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+        MaxCallWeight::set(Perbill::from_percent(30) * max_block);
+        System::set_block_number(102);
+        SimpleGovernance::on_initialize(102);
+        assert_eq!(SimpleGovernance::active_proposals().to_vec(), vec![1]);
+
+        // The call proposal is the first evaluated in the next block
+        System::set_block_number(103);
+        SimpleGovernance::on_initialize(103);
+        assert!(SimpleGovernance::proposals(1).unwrap().approved);
+        assert!(SimpleGovernance::active_proposals().is_empty());
+    });
+}
+
+#[test]
+fn on_idle_closes_backlog_as_weight_allows() {
+    new_test_ext().execute_with(|| {
+        MaxProposalsPerBlock::set(1);
+        System::set_block_number(1);
+        
+        for proposer in 1..=3u64 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(proposer),
+                b"Test proposal".to_vec()
+            ));
+        }
+        
+        System::set_block_number(102);
+        SimpleGovernance::on_initialize(102);
+        assert_eq!(SimpleGovernance::active_proposals().len(), 2);
+        
+        // Without spare weight nothing is processed
+        assert_eq!(SimpleGovernance::on_idle(102, Weight::zero()), Weight::zero());
+        assert_eq!(SimpleGovernance::active_proposals().len(), 2);
+        
+        // With enough weight the whole backlog is closed
+        let used = SimpleGovernance::on_idle(102, Weight::MAX);
+        assert!(used.any_gt(Weight::zero()));
+        assert!(SimpleGovernance::active_proposals().is_empty());
+        assert!(SimpleGovernance::proposals(1).unwrap().is_closed);
+        assert!(SimpleGovernance::proposals(2).unwrap().is_closed);
+    });
+}
+
+#[test]
+fn propose_spend_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        let vesting = SpendVesting { per_block: 10, starting_block: 200 };
+        assert_ok!(SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Fund the explorer".to_vec(),
+            7,
+            500,
+            Some(vesting.clone())
+        ));
+        assert_eq!(
+            SimpleGovernance::proposal_actions(0),
+            Some(ProposalAction::Spend { beneficiary: 7, amount: 500, vesting: Some(vesting) })
+        );
+        
+        // Spends are limited per track, and the fast track allows none
+        assert_noop!(
+            SimpleGovernance::propose_spend(RuntimeOrigin::signed(2), 0, b"Test".to_vec(), 7, 1001, None),
+            Error::<Test>::SpendExceedsTrackLimit
+        );
+        assert_noop!(
+            SimpleGovernance::propose_spend(RuntimeOrigin::signed(2), 1, b"Test".to_vec(), 7, 1, None),
+            Error::<Test>::SpendExceedsTrackLimit
+        );
+        assert_noop!(
+            SimpleGovernance::propose_spend(RuntimeOrigin::signed(2), 0, b"Test".to_vec(), 7, 0, None),
+            Error::<Test>::SpendExceedsTrackLimit
+        );
+        assert_noop!(
+            SimpleGovernance::propose_spend(
+                RuntimeOrigin::signed(2),
+                0,
+                b"Test".to_vec(),
+                7,
+                500,
+                Some(SpendVesting { per_block: 0, starting_block: 200 })
+            ),
+            Error::<Test>::InvalidVestingSchedule
+        );
+    });
+}
+
+#[test]
+fn approved_spend_is_paid_from_treasury() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Fund the explorer".to_vec(),
+            7,
+            500,
+            None
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        
+        run_to_block(102);
+        assert!(SimpleGovernance::proposals(0).unwrap().approved);
+        assert_eq!(Balances::free_balance(7), 500);
+        assert_eq!(Balances::free_balance(TreasuryAccount::get()), 9_500);
+        // Only dispatched calls prove that governance can act
+        assert_eq!(SimpleGovernance::enacted_proposals(), 0);
+        System::assert_last_event(Event::ProposalEnacted { proposal_id: 0, result: Ok(()) }.into());
+    });
+}
+
+#[test]
+fn rejected_spend_is_not_paid() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Fund the explorer".to_vec(),
+            7,
+            500,
+            None
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, false));
+        
+        run_to_block(102);
+        assert!(!SimpleGovernance::proposals(0).unwrap().approved);
+        assert_eq!(Balances::free_balance(7), 0);
+        assert_eq!(Balances::free_balance(TreasuryAccount::get()), 10_000);
+    });
+}
+
+#[test]
+fn vested_spend_is_locked_until_vested() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Fund the explorer".to_vec(),
+            7,
+            500,
+            Some(SpendVesting { per_block: 10, starting_block: 102 })
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        
+        run_to_block(102);
+        assert_eq!(Balances::free_balance(7), 500);
+        assert_eq!(Vesting::vesting_balance(&7), Some(500));
+        
+        run_to_block(112);
+        assert_eq!(Vesting::vesting_balance(&7), Some(400));
+    });
+}
+
+#[test]
+fn failed_spend_is_rolled_back_and_reported() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Fund the explorer".to_vec(),
+            7,
+            500,
+            Some(SpendVesting { per_block: 10, starting_block: 102 })
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        
+        // The pot cannot cover the spend
+        Balances::make_free_balance_be(&TreasuryAccount::get(), 100);
+        
+        run_to_block(102);
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(proposal.approved);
+        assert_eq!(Balances::free_balance(7), 0);
+        assert_eq!(Balances::free_balance(TreasuryAccount::get()), 100);
+        assert_eq!(SimpleGovernance::enacted_proposals(), 0);
+        assert!(System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::SimpleGovernance(Event::ProposalEnacted { proposal_id: 0, result: Err(_) })
+        )));
+    });
+}
+
+#[test]
+fn fast_track_shortens_voting_period() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Patch a security hole".to_vec()
+        ));
+        
+        // Only the committee can fast-track
+        assert_noop!(
+            SimpleGovernance::fast_track(RuntimeOrigin::signed(1), 0, 20),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SimpleGovernance::fast_track(RuntimeOrigin::signed(100), 0, 9),
+            Error::<Test>::VotingPeriodTooShort
+        );
+        assert_noop!(
+            SimpleGovernance::fast_track(RuntimeOrigin::signed(100), 0, 200),
+            Error::<Test>::VotingPeriodNotShortened
+        );
+        
+        System::set_block_number(5);
+        assert_ok!(SimpleGovernance::fast_track(RuntimeOrigin::signed(100), 0, 20));
+        System::assert_last_event(Event::ProposalFastTracked { proposal_id: 0, end_block: 25 }.into());
+        
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert_eq!(proposal.end_block, 25);
+        assert!(proposal.emergency);
+        
+        // The proposal now closes at the new end block
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        run_to_block(26);
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(proposal.approved);
+        assert_eq!(proposal.close_reason, Some(CloseReason::Expired));
+    });
+}
+
+#[test]
+fn instant_origin_enacts_immediately() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Pay the auditors".to_vec(),
+            7,
+            500,
+            None
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, false));
+        
+        assert_noop!(
+            SimpleGovernance::enact_instantly(RuntimeOrigin::signed(100), 0),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SimpleGovernance::enact_instantly(RuntimeOrigin::root(), 0));
+        
+        // Approved and paid out despite the votes against it
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(proposal.approved);
+        assert!(proposal.emergency);
+        assert_eq!(proposal.close_reason, Some(CloseReason::Instant));
+        assert_eq!(Balances::free_balance(7), 500);
+        System::assert_has_event(
+            Event::ProposalClosed {
+                proposal_id: 0,
+                for_votes: 0,
+                against_votes: 1,
+                reason: CloseReason::Instant,
+                approved: true,
+            }.into()
+        );
+        System::assert_last_event(Event::ProposalEnacted { proposal_id: 0, result: Ok(()) }.into());
+        
+        assert_noop!(
+            SimpleGovernance::enact_instantly(RuntimeOrigin::root(), 0),
+            Error::<Test>::ProposalClosed
+        );
+    });
+}
+
+#[test]
+fn approved_upgrade_can_be_applied_by_anyone() {
+    new_upgrade_test_ext(2).execute_with(|| {
+        System::set_block_number(1);
+        
+        let code = b"new runtime".to_vec();
+        let code_hash = <Test as frame_system::Config>::Hashing::hash(&code);
+        assert_ok!(SimpleGovernance::propose_upgrade(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Upgrade to v2".to_vec(),
+            code_hash,
+            2
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        
+        // Nothing is authorized before the proposal passes
+        assert_noop!(
+            System::apply_authorized_upgrade(RuntimeOrigin::signed(5), code.clone()),
+            frame_system::Error::<Test>::NothingAuthorized
+        );
+        
+        run_to_block(102);
+        System::assert_has_event(
+            frame_system::Event::UpgradeAuthorized { code_hash, check_version: true }.into()
+        );
+        
+        // The blob matches what voters approved
+        assert_ok!(SimpleGovernance::verify_upgrade(0, &code));
+        assert_noop!(
+            SimpleGovernance::verify_upgrade(0, b"other runtime"),
+            Error::<Test>::UpgradeCodeHashMismatch
+        );
+        
+        assert_ok!(System::apply_authorized_upgrade(RuntimeOrigin::signed(5), code));
+        System::assert_last_event(frame_system::Event::CodeUpdated.into());
+    });
+}
+
+#[test]
+fn verify_upgrade_checks_spec_version() {
+    new_upgrade_test_ext(3).execute_with(|| {
+        System::set_block_number(1);
+        
+        let code = b"new runtime".to_vec();
+        let code_hash = <Test as frame_system::Config>::Hashing::hash(&code);
+        assert_ok!(SimpleGovernance::propose_upgrade(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Upgrade to v2".to_vec(),
+            code_hash,
+            2
+        ));
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(2), b"Test".to_vec()));
+        
+        // The code announces spec version 3 while voters were told 2
+        assert_noop!(
+            SimpleGovernance::verify_upgrade(0, &code),
+            Error::<Test>::UpgradeSpecVersionMismatch
+        );
+        assert_noop!(
+            SimpleGovernance::verify_upgrade(1, &code),
+            Error::<Test>::NotAnUpgradeProposal
+        );
+    });
+}
+
+/// Dispatch an approved call so governance is known to work, ending at block 102.
+fn enact_call() {
+    let parameters = GovernanceParameters {
+        max_description_length: 64,
+        voting_period: 40,
+        max_proposals_per_block: 1,
+    };
+    let call: RuntimeCall = crate::Call::<Test, Instance1>::set_parameters { parameters }.into();
+    assert_ok!(SimpleGovernance::propose_call(
+        RuntimeOrigin::signed(1),
+        1,
+        b"Tune the technical body".to_vec(),
+        Box::new(call)
+    ));
+    let proposal_id = SimpleGovernance::next_proposal_id() - 1;
+    assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), proposal_id, true));
+    run_to_block(102);
+    assert_eq!(SimpleGovernance::enacted_proposals(), 1);
+}
+
+#[test]
+fn sudo_key_removal_requires_an_enacted_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_noop!(
+            SimpleGovernance::propose_remove_sudo_key(
+                RuntimeOrigin::signed(3),
+                0,
+                b"Retire sudo".to_vec()
+            ),
+            Error::<Test>::NoProposalEnacted
+        );
+
+        // Spends and failed calls do not prove that governance can act
+        assert_ok!(SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Fund the explorer".to_vec(),
+            7,
+            500,
+            None
+        ));
+        // The technical body's parameters can only be set on the fast track
+        let parameters = TechnicalGovernance::parameters();
+        let call: RuntimeCall =
+            crate::Call::<Test, Instance1>::set_parameters { parameters }.into();
+        assert_ok!(SimpleGovernance::propose_call(
+            RuntimeOrigin::signed(2),
+            0,
+            b"Wrong track".to_vec(),
+            Box::new(call)
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, true));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 1, true));
+        run_to_block(102);
+        assert_eq!(Balances::free_balance(7), 500);
+        System::assert_has_event(
+            Event::ProposalEnacted { proposal_id: 1, result: Err(DispatchError::BadOrigin) }
+                .into(),
+        );
+        assert_eq!(SimpleGovernance::enacted_proposals(), 0);
+        assert_noop!(
+            SimpleGovernance::propose_remove_sudo_key(
+                RuntimeOrigin::signed(3),
+                0,
+                b"Retire sudo".to_vec()
+            ),
+            Error::<Test>::NoProposalEnacted
+        );
+    });
+}
+
+#[test]
+fn sudo_key_removal_follows_a_dispatched_call() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        enact_call();
+        
+        assert_ok!(SimpleGovernance::propose_remove_sudo_key(
+            RuntimeOrigin::signed(3),
+            0,
+            b"Retire sudo".to_vec()
+        ));
+        assert_eq!(SimpleGovernance::proposal_actions(1), Some(ProposalAction::RemoveSudoKey));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 1, true));
+        
+        run_to_block(203);
+        assert!(!SudoKeySet::get());
+        assert_eq!(SimpleGovernance::enacted_proposals(), 1);
+        System::assert_last_event(Event::ProposalEnacted { proposal_id: 1, result: Ok(()) }.into());
+    });
+}
+
+#[test]
+fn sudo_key_removal_needs_a_sudo_key() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        enact_call();
+        
+        assert_ok!(SimpleGovernance::propose_remove_sudo_key(
+            RuntimeOrigin::signed(3),
+            0,
+            b"Retire sudo".to_vec()
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 1, true));
+        
+        // The key is removed by other means before the proposal is enacted
+        SudoKeySet::set(false);
+        assert_noop!(
+            SimpleGovernance::propose_remove_sudo_key(
+                RuntimeOrigin::signed(3),
+                0,
+                b"Retire sudo".to_vec()
+            ),
+            Error::<Test>::NoSudoKey
+        );
+        
+        run_to_block(203);
+        assert!(SimpleGovernance::proposals(1).unwrap().approved);
+        assert_eq!(SimpleGovernance::enacted_proposals(), 1);
+        System::assert_last_event(
+            Event::ProposalEnacted {
+                proposal_id: 1,
+                result: Err(Error::<Test>::NoSudoKey.into()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn propose_origin_is_required() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            SimpleGovernance::propose(RuntimeOrigin::none(), b"Test".to_vec()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            SimpleGovernance::propose_on_track(RuntimeOrigin::root(), 0, b"Test".to_vec()),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn committee_change_proposals_change_members() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose_committee_change(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Elect 10".to_vec(),
+            MembershipChange::Add(10)
+        ));
+        assert_eq!(
+            SimpleGovernance::proposal_actions(0),
+            Some(ProposalAction::ChangeCommittee(MembershipChange::Add(10)))
+        );
+        assert_ok!(SimpleGovernance::propose_committee_change(
+            RuntimeOrigin::signed(2),
+            0,
+            b"Remove 11".to_vec(),
+            MembershipChange::Remove(11)
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, true));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 1, true));
+        
+        run_to_block(102);
+        assert_eq!(CommitteeMembers::get(), vec![10]);
+        System::assert_has_event(Event::ProposalEnacted { proposal_id: 0, result: Ok(()) }.into());
+        
+        // 11 is not a member, so the approved change fails
+        System::assert_has_event(
+            Event::ProposalEnacted {
+                proposal_id: 1,
+                result: Err(DispatchError::Other("not a member")),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn validator_change_proposals_change_validators() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose_validator_change(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Add validator 3".to_vec(),
+            MembershipChange::Add(3)
+        ));
+        assert_eq!(
+            SimpleGovernance::proposal_actions(0),
+            Some(ProposalAction::ChangeValidators(MembershipChange::Add(3)))
+        );
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(4), 0, true));
+        
+        run_to_block(102);
+        assert_eq!(ValidatorSet::get(), vec![1, 2, 3]);
+        System::assert_last_event(Event::ProposalEnacted { proposal_id: 0, result: Ok(()) }.into());
+        
+        // Removals below the minimum fail on enactment
+        assert_ok!(SimpleGovernance::propose_validator_change(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Remove validator 1".to_vec(),
+            MembershipChange::Remove(1)
+        ));
+        assert_ok!(SimpleGovernance::propose_validator_change(
+            RuntimeOrigin::signed(2),
+            0,
+            b"Remove validator 2".to_vec(),
+            MembershipChange::Remove(2)
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(4), 1, true));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(4), 2, true));
+        
+        run_to_block(203);
+        assert_eq!(ValidatorSet::get(), vec![2, 3]);
+        System::assert_has_event(Event::ProposalEnacted { proposal_id: 1, result: Ok(()) }.into());
+        System::assert_has_event(
+            Event::ProposalEnacted {
+                proposal_id: 2,
+                result: Err(DispatchError::Other("too few validators")),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn offender_removal_proposals_remove_reported_validators() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        ValidatorSet::set(vec![1, 2, 3]);
+        
+        assert_noop!(
+            SimpleGovernance::propose_offender_removal(
+                RuntimeOrigin::signed(4),
+                0,
+                b"Remove validator 3".to_vec(),
+                3
+            ),
+            Error::<Test>::NotAnOffender
+        );
+        
+        // Validator 3 equivocated
+        Offenders::set(vec![3]);
+        assert_ok!(SimpleGovernance::propose_offender_removal(
+            RuntimeOrigin::signed(4),
+            0,
+            b"Remove validator 3".to_vec(),
+            3
+        ));
+        assert_eq!(SimpleGovernance::proposal_actions(0), Some(ProposalAction::RemoveOffender(3)));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(5), 0, true));
+        
+        run_to_block(102);
+        assert_eq!(ValidatorSet::get(), vec![1, 2]);
+        System::assert_last_event(Event::ProposalEnacted { proposal_id: 0, result: Ok(()) }.into());
+    });
+}
+
+#[test]
+fn cancel_origin_cancels_proposal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Fund the explorer".to_vec(),
+            7,
+            500,
+            None
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        
+        // Only the committee can cancel
+        assert_noop!(SimpleGovernance::cancel(RuntimeOrigin::signed(1), 0), DispatchError::BadOrigin);
+        assert_noop!(
+            SimpleGovernance::cancel(RuntimeOrigin::signed(100), 1),
+            Error::<Test>::ProposalNotFound
+        );
+        
+        assert_ok!(SimpleGovernance::cancel(RuntimeOrigin::signed(100), 0));
+        System::assert_last_event(
+            Event::ProposalClosed {
+                proposal_id: 0,
+                for_votes: 1,
+                against_votes: 0,
+                reason: CloseReason::Cancelled,
+                approved: false,
+            }
+            .into(),
+        );
+        
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(!proposal.approved);
+        assert!(SimpleGovernance::active_proposals().is_empty());
+        assert_eq!(SimpleGovernance::active_proposals_of(1), 0);
+        assert_noop!(
+            SimpleGovernance::cancel(RuntimeOrigin::signed(100), 0),
+            Error::<Test>::ProposalClosed
+        );
+        
+        // The cancelled spend is never paid
+        run_to_block(102);
+        assert_eq!(Balances::free_balance(7), 0);
+    });
+}
+
+#[test]
+fn close_expired_proposal_task_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        
+        let task = GovernanceTask::<Test>::CloseExpiredProposal { proposal_id: 0 };
+        assert_eq!(GovernanceTask::<Test>::iter().count(), 2);
+        
+        // Not valid while the proposal is still being voted on
+        assert!(!task.is_valid());
+        assert_noop!(
+            System::do_task(RuntimeOrigin::none(), task.clone().into()),
+            frame_system::Error::<Test>::InvalidTask
+        );
+        
+        // Anyone can submit it as an unsigned extrinsic once the period has ended
+        System::set_block_number(102);
+        assert!(task.is_valid());
+        assert_ok!(System::do_task(RuntimeOrigin::none(), task.clone().into()));
+        
+        let proposal = SimpleGovernance::proposals(0).unwrap();
+        assert!(proposal.is_closed);
+        assert!(proposal.approved);
+        assert!(SimpleGovernance::active_proposals().is_empty());
+        
+        // Closing twice is not possible
+        assert!(!task.is_valid());
+        assert_noop!(task.run(), Error::<Test>::ProposalNotClosable);
+    });
+}
+
+#[test]
+fn clean_up_votes_task_removes_votes_in_batches() {
+    new_test_ext().execute_with(|| {
+        MaxVotesRemovedPerTask::set(2);
+        System::set_block_number(1);
+        
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        for voter in 2..=4u64 {
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(voter), 0, true));
+        }
+        
+        // Votes of open proposals are kept
+        let task = GovernanceTask::<Test>::CleanUpVotes { proposal_id: 0 };
+        assert!(!task.is_valid());
+        assert_noop!(task.run(), Error::<Test>::ProposalNotClosed);
+        
+        System::set_block_number(102);
+        assert_ok!(SimpleGovernance::close_proposal(RuntimeOrigin::signed(1), 0));
+        assert!(task.is_valid());
+        
+        assert_ok!(System::do_task(RuntimeOrigin::none(), task.clone().into()));
+        System::assert_last_event(
+            Event::VotesCleanedUp { proposal_id: 0, removed: 2, complete: false }.into()
+        );
+        
+        assert_ok!(System::do_task(RuntimeOrigin::none(), task.clone().into()));
+        System::assert_last_event(
+            Event::VotesCleanedUp { proposal_id: 0, removed: 1, complete: true }.into()
+        );
+        
+        // The tally and participation survive and there is nothing left to clean up
+        assert_eq!(SimpleGovernance::votes(0, 2), None);
+        assert_eq!(SimpleGovernance::votes_by_account(2, 0), None);
+        assert_eq!(SimpleGovernance::vote_tallies(0).unwrap().for_votes, 3);
+        assert_eq!(SimpleGovernance::participation_of(2).for_votes, 1);
+        assert!(!task.is_valid());
+    });
+}
+
+#[test]
+fn vote_with_reason_records_conviction_and_reason() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(3);
+        Balances::make_free_balance_be(&2, 500);
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+
+        let reason = VoteReason::Text(b"Keeps fees low".to_vec().try_into().unwrap());
+        assert_ok!(SimpleGovernance::vote_with_reason(
+            RuntimeOrigin::signed(2),
+            0,
+            true,
+            Conviction::Locked3x,
+            Some(reason.clone())
+        ));
+        let hashed = VoteReason::Hash(<Test as frame_system::Config>::Hashing::hash(b"Why"));
+        assert_ok!(SimpleGovernance::vote_with_reason(
+            RuntimeOrigin::signed(3),
+            0,
+            false,
+            Conviction::Locked6x,
+            Some(hashed.clone())
+        ));
+
+        let record = VoteRecord {
+            vote: true,
+            block: 3,
+            balance: 500,
+            conviction: Conviction::Locked3x,
+            reason: Some(reason.clone()),
+        };
+        assert_eq!(SimpleGovernance::votes(0, 2), Some(record.clone()));
+        assert_eq!(SimpleGovernance::votes_by_account(2, 0), Some(record));
+        assert_eq!(SimpleGovernance::votes(0, 3).unwrap().reason, Some(hashed));
+        System::assert_has_event(
+            Event::Voted {
+                proposal_id: 0,
+                voter: 2,
+                vote: true,
+                block: 3,
+                balance: 500,
+                conviction: Conviction::Locked3x,
+                reason: Some(reason),
+            }
+            .into(),
+        );
+
+        // Conviction does not weigh in the tally
+        let tally = SimpleGovernance::vote_tallies(0).unwrap();
+        assert_eq!((tally.for_votes, tally.against_votes), (1, 1));
+
+        assert_noop!(
+            SimpleGovernance::vote_with_reason(
+                RuntimeOrigin::signed(2),
+                0,
+                false,
+                Conviction::None,
+                None
+            ),
+            Error::<Test>::AlreadyVoted
+        );
+    });
+}
+
+#[test]
+fn votes_are_indexed_by_account_and_paged() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for proposer in 1..=4u64 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(proposer),
+                b"Test proposal".to_vec()
+            ));
+        }
+
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(5), 0, true));
+        System::set_block_number(2);
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(5), 1, false));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(5), 2, true));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(6), 2, false));
+
+        let record = SimpleGovernance::votes_by_account(5, 1).unwrap();
+        assert_eq!((record.vote, record.block), (false, 2));
+        assert_eq!(SimpleGovernance::votes(1, 5), Some(record));
+        assert_eq!(SimpleGovernance::votes_by_account(5, 3), None);
+
+        // Paging returns every vote exactly once
+        let first = SimpleGovernance::votes_of(&5, None, 2);
+        assert_eq!(first.len(), 2);
+        let second = SimpleGovernance::votes_of(&5, Some(first[1].0), 2);
+        assert_eq!(second.len(), 1);
+        let mut ids: Vec<_> = first.iter().chain(&second).map(|(id, _)| *id).collect();
+        ids.sort();
+        assert_eq!(ids, vec![0, 1, 2]);
+        assert_eq!(SimpleGovernance::votes_of(&5, Some(second[0].0), 2), vec![]);
+        let votes = SimpleGovernance::votes_of(&6, None, 10);
+        assert_eq!(votes.len(), 1);
+        assert_eq!((votes[0].0, votes[0].1.vote), (2, false));
+
+        assert_eq!(
+            SimpleGovernance::participation(&5),
+            ParticipationStats {
+                votes: VoteTally { for_votes: 2, against_votes: 1 },
+                proposals: 4,
+                rate: Perbill::from_percent(75),
+            }
+        );
+        assert_eq!(SimpleGovernance::participation(&7).rate, Perbill::zero());
+    });
+}
+
+#[test]
+fn participation_is_zero_before_the_first_proposal() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(
+            SimpleGovernance::participation(&1),
+            ParticipationStats {
+                votes: VoteTally::default(),
+                proposals: 0,
+                rate: Perbill::zero(),
+            }
+        );
+    });
+}
+
+#[test]
+fn first_votes_on_open_proposals_are_free_up_to_a_cap() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for proposer in 1..=2u64 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(proposer),
+                b"Test proposal".to_vec()
+            ));
+        }
+        let is_free = |voter: u64, proposal_id: u32| {
+            let call: RuntimeCall = crate::Call::<Test>::vote { proposal_id, vote: true }.into();
+            call.is_feeless(&RuntimeOrigin::signed(voter))
+        };
+
+        assert!(is_free(3, 0));
+        assert!(!is_free(3, 5));
+        assert!(!crate::Call::<Test>::vote { proposal_id: 0, vote: true }
+            .is_feeless(&RuntimeOrigin::root()));
+
+        // One free vote per proposal and account
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, true));
+        assert!(!is_free(3, 0));
+        assert!(is_free(3, 1));
+
+        // At most `MaxFreeVotesPerBlock` free votes per block
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(4), 0, true));
+        assert_eq!(crate::FreeVotes::<Test>::get(), Some((1, 2)));
+        assert!(!is_free(3, 1));
+        assert!(!is_free(5, 0));
+
+        // Votes beyond the cap still count, but pay fees
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(5), 0, false));
+        assert_eq!(crate::FreeVotes::<Test>::get(), Some((1, 2)));
+
+        System::set_block_number(2);
+        assert!(is_free(3, 1));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 1, true));
+        assert_eq!(crate::FreeVotes::<Test>::get(), Some((2, 1)));
+
+        // Other votes are never free
+        MaxFreeVotesPerBlock::set(0);
+        assert!(!is_free(4, 1));
+        let call: RuntimeCall = crate::Call::<Test>::vote_with_reason {
+            proposal_id: 1,
+            vote: true,
+            conviction: Conviction::None,
+            reason: None,
+        }
+        .into();
+        assert!(!call.is_feeless(&RuntimeOrigin::signed(4)));
+
+        // Ended voting periods are not free
+        MaxFreeVotesPerBlock::set(2);
+        System::set_block_number(102);
+        assert!(!is_free(4, 1));
+    });
+}
+
+/// A ballot from `voter` for the mock chain, signed with `TestSignature`.
+fn signed_ballot(
+    voter: u64,
+    proposal_id: u32,
+    vote: bool,
+    nonce: u32,
+) -> (Ballot<u64, H256>, TestSignature) {
+    let ballot = Ballot { voter, proposal_id, vote, nonce, genesis_hash: System::block_hash(0) };
+    let signature = TestSignature(voter, SimpleGovernance::ballot_payload(&ballot));
+    (ballot, signature)
+}
+
+#[test]
+fn vote_signed_casts_relayed_ballots_once() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for proposer in 1..=2u64 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(proposer),
+                b"Test proposal".to_vec()
+            ));
+        }
+
+        let (ballot, signature) = signed_ballot(7, 0, true, 0);
+        assert_noop!(
+            SimpleGovernance::vote_signed(
+                RuntimeOrigin::signed(1),
+                ballot.clone(),
+                signature.clone()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SimpleGovernance::vote_signed(
+            RuntimeOrigin::none(),
+            ballot.clone(),
+            signature.clone()
+        ));
+        assert!(SimpleGovernance::votes(0, 7).unwrap().vote);
+        assert_eq!(SimpleGovernance::ballot_nonce(7), 1);
+        System::assert_last_event(
+            Event::Voted {
+                proposal_id: 0,
+                voter: 7,
+                vote: true,
+                block: 1,
+                balance: 0,
+                conviction: Conviction::None,
+                reason: None,
+            }
+            .into(),
+        );
+
+        // The same ballot cannot be replayed, even on another proposal
+        assert_noop!(
+            SimpleGovernance::vote_signed(RuntimeOrigin::none(), ballot, signature),
+            Error::<Test>::BallotNonceMismatch
+        );
+
+        // Ballots signed by someone else or for another chain are rejected
+        let (mut ballot, _) = signed_ballot(7, 1, false, 1);
+        assert_noop!(
+            SimpleGovernance::vote_signed(
+                RuntimeOrigin::none(),
+                ballot.clone(),
+                TestSignature(8, SimpleGovernance::ballot_payload(&ballot))
+            ),
+            Error::<Test>::InvalidBallot
+        );
+        ballot.genesis_hash = H256::repeat_byte(1);
+        let signature = TestSignature(7, SimpleGovernance::ballot_payload(&ballot));
+        assert_noop!(
+            SimpleGovernance::vote_signed(RuntimeOrigin::none(), ballot, signature),
+            Error::<Test>::InvalidBallot
+        );
+
+        let (ballot, signature) = signed_ballot(7, 1, false, 1);
+        assert_ok!(SimpleGovernance::vote_signed(RuntimeOrigin::none(), ballot, signature));
+        assert_eq!(SimpleGovernance::vote_tallies(1).unwrap().against_votes, 1);
+    });
+}
+
+#[test]
+fn relayed_ballots_are_validated_for_the_pool() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for proposer in 1..=3u64 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(proposer),
+                b"Test proposal".to_vec()
+            ));
+        }
+        let validate = |ballot: Ballot<u64, H256>, signature: TestSignature| {
+            let call = crate::Call::vote_signed { ballot, signature };
+            SimpleGovernance::validate_unsigned(TransactionSource::External, &call)
+        };
+
+        let (ballot, signature) = signed_ballot(7, 0, true, 0);
+        let valid = validate(ballot.clone(), signature.clone()).unwrap();
+        assert!(valid.requires.is_empty());
+        assert_eq!(valid.provides.len(), 1);
+
+        // A later ballot waits for the earlier one
+        let (next, next_signature) = signed_ballot(7, 1, true, 1);
+        let waiting = validate(next.clone(), next_signature.clone()).unwrap();
+        assert_eq!(waiting.requires, valid.provides);
+
+        // Forged ballots are rejected
+        assert_eq!(
+            validate(ballot.clone(), TestSignature(8, SimpleGovernance::ballot_payload(&ballot))),
+            Err(InvalidTransaction::BadProof.into())
+        );
+
+        // Used nonces and cast votes are stale
+        assert_ok!(SimpleGovernance::vote_signed(
+            RuntimeOrigin::none(),
+            ballot.clone(),
+            signature.clone()
+        ));
+        assert_eq!(validate(ballot, signature), Err(InvalidTransaction::Stale.into()));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(7), 1, false));
+        assert_eq!(validate(next, next_signature), Err(InvalidTransaction::Stale.into()));
+
+        // A ballot for the default instance is not valid on the technical body
+        assert_ok!(TechnicalGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Technical".to_vec()
+        ));
+        let (ballot, signature) = signed_ballot(9, 0, true, 0);
+        let call = crate::Call::<Test, Instance1>::vote_signed { ballot, signature };
+        assert_eq!(
+            TechnicalGovernance::validate_unsigned(TransactionSource::External, &call),
+            Err(InvalidTransaction::BadProof.into())
+        );
+    });
+}
+
+#[test]
+fn offchain_worker_submits_unsigned_closure() {
+    let (offchain, _) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        
+        // Nothing to close while the proposal is open
+        let call = crate::Call::close_proposal { proposal_id: 0 };
+        SimpleGovernance::offchain_worker(1);
+        assert!(pool_state.read().transactions.is_empty());
+        assert_eq!(
+            SimpleGovernance::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::Future.into()
+        );
+        
+        // Without a local key the closure is submitted unsigned
+        System::set_block_number(102);
+        SimpleGovernance::offchain_worker(102);
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        assert!(matches!(tx.preamble, Preamble::Bare(_)));
+        assert_eq!(tx.function, RuntimeCall::SimpleGovernance(call.clone()));
+        assert_ok!(SimpleGovernance::validate_unsigned(TransactionSource::External, &call));
+        
+        // It is not resubmitted while the first transaction may still be in the pool
+        SimpleGovernance::offchain_worker(103);
+        assert!(pool_state.read().transactions.is_empty());
+        
+        assert_ok!(SimpleGovernance::close_proposal(RuntimeOrigin::none(), 0));
+        assert_eq!(
+            SimpleGovernance::validate_unsigned(TransactionSource::External, &call),
+            InvalidTransaction::Stale.into()
+        );
+    });
+}
+
+#[test]
+fn offchain_worker_signs_closure_with_local_key() {
+    let (offchain, _) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+    
+    ext.execute_with(|| {
+        UintAuthorityId::set_all_keys(vec![7u64]);
+        System::set_block_number(1);
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        
+        System::set_block_number(102);
+        SimpleGovernance::offchain_worker(102);
+        
+        let tx = pool_state.write().transactions.pop().unwrap();
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        assert!(matches!(tx.preamble, Preamble::Signed(7, ..)));
+        assert_eq!(
+            tx.function,
+            RuntimeCall::SimpleGovernance(crate::Call::close_proposal { proposal_id: 0 })
+        );
+    });
+}
+
+#[test]
+fn offchain_worker_posts_closed_results() {
+    let (offchain, offchain_state) = TestOffchainExt::new();
+    let mut ext = new_test_ext();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    
+    offchain_state.write().expect_request(PendingRequest {
+        method: "POST".into(),
+        uri: "http://localhost:8080/results".into(),
+        headers: vec![("Content-Type".into(), "application/json".into())],
+        body: br#"{"pallet":"SimpleGovernance","proposal_id":0,"for_votes":1,"against_votes":0,"approved":true,"reason":"expired","block":102}"#.to_vec(),
+        response: Some(b"{}".to_vec()),
+        sent: true,
+        ..Default::default()
+    });
+    
+    ext.execute_with(|| {
+        sp_io::offchain::local_storage_set(
+            StorageKind::PERSISTENT,
+            RESULTS_ENDPOINT_KEY,
+            b"http://localhost:8080/results",
+        );
+        
+        System::set_block_number(1);
+        assert_ok!(SimpleGovernance::propose(
+            RuntimeOrigin::signed(1),
+            b"Test proposal".to_vec()
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        
+        // The hooks close the proposal and the worker publishes the result
+        run_to_block(102);
+        assert!(SimpleGovernance::proposals(0).unwrap().is_closed);
+        SimpleGovernance::offchain_worker(102);
+    });
+}
+
+#[test]
+fn multiple_proposals_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        // Create multiple proposals
+        for i in 0..5 {
+            assert_ok!(SimpleGovernance::propose(
+                RuntimeOrigin::signed(1),
+                format!("Proposal {}", i).as_bytes().to_vec()
+            ));
+        }
+        
+        // Check all proposals were created
+        for i in 0..5 {
+            assert!(SimpleGovernance::proposals(i).is_some());
+        }
+        
+        assert_eq!(SimpleGovernance::next_proposal_id(), 5);
+    });
+}
+
+#[test]
+fn genesis_config_works() {
+    // Test that genesis config can initialize proposals
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    
+    crate::GenesisConfig::<Test> {
+        proposals: vec![
+            (1u64, b"Genesis proposal 1".to_vec()),
+            (2u64, b"Genesis proposal 2".to_vec()),
+        ],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    
+    let mut ext = sp_io::TestExternalities::from(t);
+    ext.execute_with(|| {
+        // Check proposals were created
+        assert_eq!(SimpleGovernance::next_proposal_id(), 2);
+        
+        let proposal1 = SimpleGovernance::proposals(0).unwrap();
+        assert_eq!(proposal1.proposer, 1u64);
+        assert_eq!(proposal1.description.into_inner(), b"Genesis proposal 1".to_vec());
+        
+        let proposal2 = SimpleGovernance::proposals(1).unwrap();
+        assert_eq!(proposal2.proposer, 2u64);
+        assert_eq!(proposal2.description.into_inner(), b"Genesis proposal 2".to_vec());
+    });
+}
+
+#[test]
+fn integrity_test_accepts_the_mock_config() {
+    <SimpleGovernance as IntegrityTest>::integrity_test();
+    <TechnicalGovernance as IntegrityTest>::integrity_test();
+}
+
+#[test]
+#[should_panic(expected = "MaxProposalsPerBlock must be between 1 and MaxActiveProposals")]
+fn integrity_test_rejects_more_proposals_per_block_than_can_be_open() {
+    MaxProposalsPerBlock::set(9);
+    <SimpleGovernance as IntegrityTest>::integrity_test();
+}
+
+#[test]
+fn instances_are_independent() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Community".to_vec()));
+        assert_ok!(TechnicalGovernance::propose(RuntimeOrigin::signed(1), b"Technical".to_vec()));
+
+        // Each instance numbers its proposals and applies its own voting period
+        assert_eq!(SimpleGovernance::proposals(0).unwrap().end_block, 101);
+        assert_eq!(TechnicalGovernance::proposals(0).unwrap().end_block, 21);
+        System::assert_last_event(RuntimeEvent::TechnicalGovernance(Event::ProposalCreated {
+            proposal_id: 0,
+            proposer: 1,
+            description: b"Technical".to_vec().try_into().unwrap(),
+            end_block: 21,
+            track: 0,
+        }));
+
+        // Votes are kept per instance
+        assert_ok!(TechnicalGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        assert!(TechnicalGovernance::votes(0, 2).unwrap().vote);
+        assert_eq!(SimpleGovernance::votes(0, 2), None);
+        assert_eq!(SimpleGovernance::vote_tallies(0).unwrap().for_votes, 0);
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, false));
+
+        // The technical proposal closes while the community one stays open
+        run_to_block(22);
+        assert!(TechnicalGovernance::proposals(0).unwrap().approved);
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+
+        // So do the open-proposal limits
+        assert_ok!(TechnicalGovernance::propose(RuntimeOrigin::signed(1), b"One".to_vec()));
+        assert_ok!(TechnicalGovernance::propose(RuntimeOrigin::signed(2), b"Two".to_vec()));
+        assert_noop!(
+            TechnicalGovernance::propose(RuntimeOrigin::signed(3), b"Three".to_vec()),
+            Error::<Test, Instance1>::TooManyActiveProposals
+        );
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(3), b"Three".to_vec()));
+    });
+}
+
+#[test]
+fn instances_pay_spends_within_their_own_limits() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_noop!(
+            TechnicalGovernance::propose_spend(
+                RuntimeOrigin::signed(100),
+                0,
+                b"Too much".to_vec(),
+                7,
+                1001,
+                None
+            ),
+            Error::<Test, Instance1>::SpendExceedsTrackLimit
+        );
+        assert_ok!(TechnicalGovernance::propose_spend(
+            RuntimeOrigin::signed(100),
+            0,
+            b"Fund the audit".to_vec(),
+            7,
+            300,
+            None
+        ));
+        assert_eq!(
+            TechnicalGovernance::proposal_actions(0),
+            Some(ProposalAction::Spend { beneficiary: 7, amount: 300, vesting: None })
+        );
+        assert_eq!(SimpleGovernance::proposal_actions(0), None);
+
+        assert_ok!(TechnicalGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        run_to_block(22);
+        assert!(TechnicalGovernance::proposals(0).unwrap().approved);
+        assert_eq!(Balances::free_balance(7), 300);
+        assert_eq!(Balances::free_balance(TreasuryAccount::get()), 9_700);
+        assert_eq!(
+            TechnicalGovernance::proposals(0).unwrap().close_reason,
+            Some(CloseReason::Expired)
+        );
+    });
+}
+
+#[test]
+fn action_proposals_require_the_action_propose_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        // Anyone may propose on the technical body, but only its committee may attach actions
+        assert_ok!(TechnicalGovernance::propose(RuntimeOrigin::signed(1), b"Text".to_vec()));
+        assert_noop!(
+            TechnicalGovernance::propose_spend(
+                RuntimeOrigin::signed(1),
+                0,
+                b"Fund the audit".to_vec(),
+                7,
+                300,
+                None
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            TechnicalGovernance::propose_upgrade(
+                RuntimeOrigin::signed(1),
+                0,
+                b"Upgrade".to_vec(),
+                H256::repeat_byte(1),
+                2
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            TechnicalGovernance::propose_remove_sudo_key(
+                RuntimeOrigin::signed(1),
+                0,
+                b"Remove sudo".to_vec()
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(TechnicalGovernance::propose_remove_sudo_key(
+            RuntimeOrigin::signed(100),
+            0,
+            b"Remove sudo".to_vec()
+        ));
+    });
+}
+
+#[test]
+fn unsigned_closures_of_instances_do_not_conflict() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Community".to_vec()));
+        assert_ok!(TechnicalGovernance::propose(RuntimeOrigin::signed(1), b"Technical".to_vec()));
+        System::set_block_number(102);
+
+        let community = SimpleGovernance::validate_unsigned(
+            TransactionSource::External,
+            &crate::Call::close_proposal { proposal_id: 0 },
+        )
+        .unwrap();
+        let technical = TechnicalGovernance::validate_unsigned(
+            TransactionSource::External,
+            &crate::Call::close_proposal { proposal_id: 0 },
+        )
+        .unwrap();
+
+        // Closures of proposals with the same ID are both kept in the pool
+        assert_ne!(community.provides, technical.provides);
+    });
+}
+
+#[test]
+fn set_parameters_applies_to_new_proposals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Before".to_vec()));
+
+        let parameters = GovernanceParameters {
+            max_description_length: 10,
+            voting_period: 50,
+            max_proposals_per_block: 2,
+        };
+        assert_noop!(
+            SimpleGovernance::set_parameters(RuntimeOrigin::signed(1), parameters),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(SimpleGovernance::set_parameters(RuntimeOrigin::root(), parameters));
+        System::assert_last_event(Event::ParametersSet { parameters }.into());
+        assert_eq!(SimpleGovernance::parameters(), parameters);
+
+        // The existing proposal keeps its voting period
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(2), b"After".to_vec()));
+        assert_eq!(SimpleGovernance::proposals(0).unwrap().end_block, 101);
+        assert_eq!(SimpleGovernance::proposals(1).unwrap().end_block, 51);
+
+        assert_noop!(
+            SimpleGovernance::propose(RuntimeOrigin::signed(3), b"Far too long".to_vec()),
+            Error::<Test>::DescriptionTooLong
+        );
+
+        // The other instance keeps its own parameters
+        assert_eq!(TechnicalGovernance::parameters().voting_period, 20);
+    });
+}
+
+#[test]
+fn set_parameters_enforces_bounds() {
+    new_test_ext().execute_with(|| {
+        let valid = GovernanceParameters {
+            max_description_length: 256,
+            voting_period: 100,
+            max_proposals_per_block: 8,
+        };
+
+        assert_noop!(
+            SimpleGovernance::set_parameters(
+                RuntimeOrigin::root(),
+                GovernanceParameters { max_description_length: 257, ..valid }
+            ),
+            Error::<Test>::DescriptionLengthOutOfBounds
+        );
+        assert_noop!(
+            SimpleGovernance::set_parameters(
+                RuntimeOrigin::root(),
+                GovernanceParameters { voting_period: 9, ..valid }
+            ),
+            Error::<Test>::VotingPeriodOutOfBounds
+        );
+        assert_noop!(
+            SimpleGovernance::set_parameters(
+                RuntimeOrigin::root(),
+                GovernanceParameters { voting_period: 1001, ..valid }
+            ),
+            Error::<Test>::VotingPeriodOutOfBounds
+        );
+        assert_noop!(
+            SimpleGovernance::set_parameters(
+                RuntimeOrigin::root(),
+                GovernanceParameters { max_proposals_per_block: 9, ..valid }
+            ),
+            Error::<Test>::ProposalsPerBlockOutOfBounds
+        );
+        assert_ok!(SimpleGovernance::set_parameters(RuntimeOrigin::root(), valid));
+    });
+}
+
+#[test]
+fn hooks_are_notified_of_proposal_lifecycle() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Test".to_vec()));
+        assert_eq!(HookCalls::get(), vec![HookCall::Created(0, 1)]);
+
+        // The hooks' weight is added to the vote's actual weight
+        let info = SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true).unwrap();
+        assert_eq!(info.actual_weight, Some(<() as WeightInfo>::vote() + HookWeight::get()));
+        assert_eq!(HookCalls::get()[1], HookCall::Voted(0, 2, true));
+
+        // And to the auto-close hooks' weight
+        System::set_block_number(102);
+        let weight = SimpleGovernance::on_initialize(102);
+        assert!(weight.all_gte(<() as WeightInfo>::close_proposal() + HookWeight::get()));
+        assert_eq!(HookCalls::get()[2], HookCall::Closed(0, true, CloseReason::Expired));
+
+        // The other instance has no hooks
+        assert_ok!(TechnicalGovernance::propose(RuntimeOrigin::signed(1), b"Test".to_vec()));
+        assert_eq!(HookCalls::get().len(), 3);
+    });
+}
+
+#[test]
+fn proposing_and_cancelling_report_the_hooks_weight() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let info = SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Test".to_vec()).unwrap();
+        assert_eq!(info.actual_weight, Some(<() as WeightInfo>::propose() + HookWeight::get()));
+
+        let info = SimpleGovernance::propose_spend(
+            RuntimeOrigin::signed(2),
+            0,
+            b"Spend".to_vec(),
+            7,
+            500,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            info.actual_weight,
+            Some(<() as WeightInfo>::propose_spend() + HookWeight::get())
+        );
+
+        let info = SimpleGovernance::cancel(RuntimeOrigin::signed(100), 0).unwrap();
+        assert_eq!(info.actual_weight, Some(<() as WeightInfo>::cancel() + HookWeight::get()));
+
+        // Without hooks, only the call's own weight is used
+        let info =
+            TechnicalGovernance::propose(RuntimeOrigin::signed(1), b"Test".to_vec()).unwrap();
+        assert_eq!(info.actual_weight, Some(<() as WeightInfo>::propose()));
+    });
+}
+
+#[test]
+fn manual_closures_refund_unused_call_weight() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Expired".to_vec()));
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(2), b"Instant".to_vec()));
+        let refunded = Some(<() as WeightInfo>::close_proposal() + HookWeight::get());
+
+        let info = SimpleGovernance::enact_instantly(RuntimeOrigin::root(), 1).unwrap();
+        assert_eq!(info.actual_weight, refunded);
+
+        System::set_block_number(102);
+        let info = SimpleGovernance::close_proposal(RuntimeOrigin::signed(3), 0).unwrap();
+        assert_eq!(info.actual_weight, refunded);
+        assert!(refunded.unwrap().all_lt(SimpleGovernance::close_weight()));
+    });
+}
+
+#[test]
+fn hooks_are_notified_of_manual_and_early_closures() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        EligibleElectorate::set(3);
+        EarlyDecisionThreshold::set(Some(Perbill::from_percent(60)));
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Early".to_vec()));
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(2), b"Cancelled".to_vec()));
+
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, false));
+        let info = SimpleGovernance::vote(RuntimeOrigin::signed(4), 0, false).unwrap();
+        assert_eq!(
+            info.actual_weight,
+            Some(
+                <() as WeightInfo>::vote() +
+                    <() as WeightInfo>::close_proposal() +
+                    HookWeight::get() * 2
+            )
+        );
+        assert_eq!(
+            HookCalls::get().last(),
+            Some(&HookCall::Closed(0, false, CloseReason::EarlyDecision))
+        );
+
+        assert_ok!(SimpleGovernance::cancel(RuntimeOrigin::signed(100), 1));
+        assert_eq!(
+            HookCalls::get().last(),
+            Some(&HookCall::Closed(1, false, CloseReason::Cancelled))
+        );
+    });
+}
+
+#[test]
+fn hook_tuples_call_every_handler() {
+    new_test_ext().execute_with(|| {
+        type Hooks = (RecordHooks, RecordHooks);
+
+        assert_eq!(<Hooks as OnProposalStatusChange<u64>>::max_weight(), HookWeight::get() * 2);
+        assert_eq!(Hooks::on_created(7, &1u64), HookWeight::get() * 2);
+        assert_eq!(HookCalls::get(), vec![HookCall::Created(7, 1), HookCall::Created(7, 1)]);
+    });
+}
+
+#[test]
+fn call_proposals_dispatch_with_governance_origin() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let parameters = GovernanceParameters {
+            max_description_length: 64,
+            voting_period: 40,
+            max_proposals_per_block: 1,
+        };
+        let call: RuntimeCall =
+            crate::Call::<Test, Instance1>::set_parameters { parameters }.into();
+
+        // The technical body's parameters can only be set through the fast track
+        assert_ok!(SimpleGovernance::propose_call(
+            RuntimeOrigin::signed(1),
+            0,
+            b"General".to_vec(),
+            Box::new(call.clone())
+        ));
+        assert_ok!(SimpleGovernance::propose_call(
+            RuntimeOrigin::signed(2),
+            1,
+            b"Fast".to_vec(),
+            Box::new(call.clone())
+        ));
+        assert_eq!(
+            SimpleGovernance::proposal_actions(1),
+            Some(ProposalAction::Dispatch(call.encode().try_into().unwrap()))
+        );
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, true));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 1, true));
+
+        run_to_block(102);
+        assert!(SimpleGovernance::proposals(0).unwrap().approved);
+        System::assert_has_event(
+            Event::ProposalEnacted { proposal_id: 0, result: Err(DispatchError::BadOrigin) }
+                .into(),
+        );
+        System::assert_has_event(Event::ProposalEnacted { proposal_id: 1, result: Ok(()) }.into());
+        System::assert_has_event(RuntimeEvent::TechnicalGovernance(Event::ParametersSet {
+            parameters,
+        }));
+        assert_eq!(TechnicalGovernance::parameters(), parameters);
+    });
+}
+
+#[test]
+fn dry_run_returns_call_effects_and_rolls_them_back() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let parameters = GovernanceParameters {
+            max_description_length: 64,
+            voting_period: 40,
+            max_proposals_per_block: 1,
+        };
+        let call: RuntimeCall =
+            crate::Call::<Test, Instance1>::set_parameters { parameters }.into();
+
+        assert_ok!(SimpleGovernance::propose_call(
+            RuntimeOrigin::signed(1),
+            1,
+            b"Fast".to_vec(),
+            Box::new(call.clone())
+        ));
+        assert_ok!(SimpleGovernance::propose_call(
+            RuntimeOrigin::signed(2),
+            0,
+            b"General".to_vec(),
+            Box::new(call)
+        ));
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(3), b"Plain".to_vec()));
+        let events = System::event_count();
+        let old_parameters = TechnicalGovernance::parameters();
+
+        let effects = SimpleGovernance::dry_run(0).unwrap();
+        assert_eq!(effects.result, Ok(()));
+        assert_eq!(
+            effects.events,
+            vec![RuntimeEvent::TechnicalGovernance(Event::ParametersSet { parameters })]
+        );
+        assert_eq!(effects.weight, <() as WeightInfo>::set_parameters());
+        assert!(effects.storage.changed());
+
+        // Nothing the call did is kept
+        assert_eq!(TechnicalGovernance::parameters(), old_parameters);
+        assert_eq!(System::event_count(), events);
+        assert!(!SimpleGovernance::proposals(0).unwrap().is_closed);
+
+        // The general track cannot set the technical body's parameters
+        let effects = SimpleGovernance::dry_run(1).unwrap();
+        assert_eq!(effects.result, Err(DispatchError::BadOrigin));
+        assert!(effects.events.is_empty());
+        assert!(!effects.storage.changed());
+
+        assert_eq!(SimpleGovernance::dry_run(2), Err(DryRunError::NotACallProposal));
+        assert_eq!(SimpleGovernance::dry_run(3), Err(DryRunError::ProposalNotFound));
+    });
+}
+
+#[test]
+fn propose_call_checks_length_and_weight() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let remark = |len: usize| -> Box<RuntimeCall> {
+            Box::new(frame_system::Call::<Test>::remark { remark: vec![0; len] }.into())
+        };
+
+        assert_noop!(
+            SimpleGovernance::propose_call(
+                RuntimeOrigin::signed(1),
+                0,
+                b"Long".to_vec(),
+                remark(1100)
+            ),
+            Error::<Test>::CallTooLong
+        );
+
+        MaxCallWeight::set(Weight::zero());
+        assert_noop!(
+            SimpleGovernance::propose_call(
+                RuntimeOrigin::signed(1),
+                0,
+                b"Heavy".to_vec(),
+                remark(10)
+            ),
+            Error::<Test>::CallTooHeavy
+        );
+
+        // A call that became too heavy by the time it is approved is not dispatched
+        MaxCallWeight::set(Weight::from_parts(1_000_000_000, 10_000));
+        assert_ok!(SimpleGovernance::propose_call(
+            RuntimeOrigin::signed(1),
+            0,
+            b"Remark".to_vec(),
+            remark(10)
+        ));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(2), 0, true));
+        MaxCallWeight::set(Weight::zero());
+        run_to_block(102);
+        System::assert_has_event(
+            Event::ProposalEnacted {
+                proposal_id: 0,
+                result: Err(Error::<Test>::CallTooHeavy.into()),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn governance_origin_guards_check_instance_and_track() {
+    new_test_ext().execute_with(|| {
+        let approved =
+            RuntimeOrigin::from(GovernanceOrigin::<()>::Approved { proposal_id: 3, track: 1 });
+        assert_eq!(EnsureApproved::<()>::try_origin(approved.clone()).ok(), Some((3, 1)));
+        assert_eq!(EnsureTrack::<1>::try_origin(approved.clone()).ok(), Some(3));
+        assert!(EnsureTrack::<0>::try_origin(approved).is_err());
+
+        // Approvals of another instance do not count
+        let technical = RuntimeOrigin::from(GovernanceOrigin::<Instance1>::Approved {
+            proposal_id: 3,
+            track: 1,
+        });
+        assert!(EnsureApproved::<()>::try_origin(technical.clone()).is_err());
+        assert_eq!(EnsureTrack::<1, Instance1>::try_origin(technical).ok(), Some(3));
+
+        assert!(EnsureApproved::<()>::try_origin(RuntimeOrigin::signed(1)).is_err());
+        assert!(EnsureApproved::<()>::try_origin(RuntimeOrigin::root()).is_err());
+    });
+}
+
+#[test]
+fn vote_many_casts_votes_and_skips_failed_ones() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        for _ in 0..3 {
+            assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Test".to_vec()));
+        }
+        assert_ok!(SimpleGovernance::cancel(RuntimeOrigin::signed(100), 2));
+
+        let votes = vec![(0, true), (2, true), (0, false), (9, true), (1, false)];
+        let info = SimpleGovernance::vote_many(
+            RuntimeOrigin::signed(3),
+            votes.try_into().unwrap(),
+        )
+        .unwrap();
+
+        System::assert_last_event(
+            Event::VotedMany {
+                voter: 3,
+                results: vec![
+                    (0, Ok(())),
+                    (2, Err(Error::<Test>::ProposalClosed.into())),
+                    (0, Err(Error::<Test>::AlreadyVoted.into())),
+                    (9, Err(Error::<Test>::ProposalNotFound.into())),
+                    (1, Ok(())),
+                ]
+                .try_into()
+                .unwrap(),
+            }
+            .into(),
+        );
+        assert!(SimpleGovernance::votes(0, 3).unwrap().vote);
+        assert!(!SimpleGovernance::votes(1, 3).unwrap().vote);
+        assert_eq!(SimpleGovernance::vote_tallies(0).unwrap().for_votes, 1);
+        assert_eq!(SimpleGovernance::participation(&3).votes.against_votes, 1);
+
+        // Only the votes that were cast and their hooks are charged
+        assert_eq!(
+            info.actual_weight,
+            Some(<() as WeightInfo>::vote_many(2) + HookWeight::get() * 2)
+        );
+
+        // Batches are bounded by `MaxBatchSize`
+        let too_many: Result<BoundedVec<_, MaxBatchSize>, _> = vec![(0, true); 5].try_into();
+        assert!(too_many.is_err());
+    });
+}
+
+#[test]
+fn close_many_closes_expired_proposals_and_skips_others() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"First".to_vec()));
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Second".to_vec()));
+        assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(3), 0, true));
+
+        System::set_block_number(50);
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(2), b"Later".to_vec()));
+
+        System::set_block_number(102);
+        let info = SimpleGovernance::close_many(
+            RuntimeOrigin::signed(5),
+            vec![0, 2, 0, 7, 1].try_into().unwrap(),
+        )
+        .unwrap();
+
+        System::assert_last_event(
+            Event::ClosedMany {
+                results: vec![
+                    (0, Ok(())),
+                    (2, Err(Error::<Test>::VotingPeriodNotEnded.into())),
+                    (0, Err(Error::<Test>::ProposalClosed.into())),
+                    (7, Err(Error::<Test>::ProposalNotFound.into())),
+                    (1, Ok(())),
+                ]
+                .try_into()
+                .unwrap(),
+            }
+            .into(),
+        );
+        System::assert_has_event(
+            Event::ProposalClosed {
+                proposal_id: 0,
+                for_votes: 1,
+                against_votes: 0,
+                reason: CloseReason::Expired,
+                approved: true,
+            }
+            .into(),
+        );
+        assert!(SimpleGovernance::proposals(1).unwrap().is_closed);
+        assert!(!SimpleGovernance::proposals(2).unwrap().is_closed);
+        assert_eq!(SimpleGovernance::active_proposals().into_inner(), vec![2]);
+
+        // Only the closures that happened and their hooks are charged
+        assert_eq!(
+            info.actual_weight,
+            Some(<() as WeightInfo>::close_many(2) + HookWeight::get() * 2)
+        );
+
+        // An unsigned batch is rejected outright
+        assert_noop!(
+            SimpleGovernance::close_many(RuntimeOrigin::none(), vec![2].try_into().unwrap()),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn vote_many_reserves_and_uses_a_single_early_closure() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        EligibleElectorate::set(1);
+        EarlyDecisionThreshold::set(Some(Perbill::from_percent(60)));
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"First".to_vec()));
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(1), b"Second".to_vec()));
+
+        // The batch reserves one closure, not one per vote
+        let votes: BoundedVec<_, MaxBatchSize> = vec![(0, true), (1, true)].try_into().unwrap();
+        let call = crate::Call::<Test>::vote_many { votes: votes.clone() };
+        assert_eq!(
+            call.get_dispatch_info().call_weight,
+            <() as WeightInfo>::vote_many(2) +
+                HookWeight::get() * 2 +
+                SimpleGovernance::close_weight()
+        );
+
+        let info = SimpleGovernance::vote_many(RuntimeOrigin::signed(3), votes).unwrap();
+        assert_eq!(
+            SimpleGovernance::proposals(0).unwrap().close_reason,
+            Some(CloseReason::EarlyDecision)
+        );
+        // The second decided proposal is left for the auto-close hooks
+        assert!(!SimpleGovernance::proposals(1).unwrap().is_closed);
+        assert!(SimpleGovernance::is_decided_early(
+            &SimpleGovernance::proposals(1).unwrap(),
+            &SimpleGovernance::vote_tallies(1).unwrap()
+        ));
+        assert_eq!(
+            info.actual_weight,
+            Some(
+                <() as WeightInfo>::vote_many(2) +
+                    <() as WeightInfo>::close_proposal() +
+                    HookWeight::get() * 3
+            )
+        );
+    });
+}
+
+#[test]
+fn close_many_dispatches_at_most_one_call() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let call: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+        for proposer in 1..=2 {
+            assert_ok!(SimpleGovernance::propose_call(
+                RuntimeOrigin::signed(proposer),
+                0,
+                b"Remark".to_vec(),
+                Box::new(call.clone())
+            ));
+        }
+        assert_ok!(SimpleGovernance::propose(RuntimeOrigin::signed(3), b"Plain".to_vec()));
+        for proposal_id in 0..3 {
+            assert_ok!(SimpleGovernance::vote(RuntimeOrigin::signed(4), proposal_id, true));
+        }
+
+        // The batch reserves the weight of one call, not one per proposal
+        let proposal_ids: BoundedVec<_, MaxBatchSize> = vec![0, 1, 2].try_into().unwrap();
+        let batch = crate::Call::<Test>::close_many { proposal_ids: proposal_ids.clone() };
+        assert_eq!(
+            batch.get_dispatch_info().call_weight,
+            <() as WeightInfo>::close_many(3) + HookWeight::get() * 3 + MaxCallWeight::get()
+        );
+
+        System::set_block_number(102);
+        assert_ok!(SimpleGovernance::close_many(RuntimeOrigin::signed(5), proposal_ids));
+        System::assert_last_event(
+            Event::ClosedMany {
+                results: vec![
+                    (0, Ok(())),
+                    (1, Err(Error::<Test>::BatchWeightExhausted.into())),
+                    (2, Ok(())),
+                ]
+                .try_into()
+                .unwrap(),
+            }
+            .into(),
+        );
+        assert!(SimpleGovernance::proposals(0).unwrap().approved);
+        assert!(!SimpleGovernance::proposals(1).unwrap().is_closed);
+        assert!(SimpleGovernance::proposals(2).unwrap().approved);
+
+        // The skipped proposal can be closed in another batch
+        assert_ok!(SimpleGovernance::close_many(
+            RuntimeOrigin::signed(5),
+            vec![1].try_into().unwrap()
+        ));
+        assert!(SimpleGovernance::proposals(1).unwrap().approved);
+    });
+}
+
+#[test]
+fn proposals_migrate_from_the_first_layout() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<SimpleGovernance>();
+        for (proposal_id, is_closed) in [(0, true), (1, false)] {
+            let proposal = crate::migrations::v0::ProposalInfo {
+                proposer: 1u64,
+                description: b"Legacy".to_vec().try_into().unwrap(),
+                start_block: 1u64,
+                end_block: 101u64,
+                is_closed,
+            };
+            frame_support::storage::unhashed::put(
+                &crate::Proposals::<Test>::hashed_key_for(proposal_id),
+                &proposal,
+            );
+            crate::VoteTallies::<Test>::insert(
+                proposal_id,
+                VoteTally { for_votes: 2, against_votes: 1 },
+            );
+        }
+
+        crate::migrations::MigrateToLatest::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<SimpleGovernance>(), 4);
+        let closed = SimpleGovernance::proposals(0).unwrap();
+        assert_eq!(closed.description.to_vec(), b"Legacy".to_vec());
+        assert_eq!(closed.end_block, 101);
+        assert!(closed.is_closed);
+        assert_eq!(closed.extensions, 0);
+        assert_eq!(closed.track, 0);
+        assert!(closed.approved);
+        assert!(!closed.emergency);
+        assert_eq!(closed.close_reason, None);
+        // Open proposals are decided by their track when they close.
+        assert!(!SimpleGovernance::proposals(1).unwrap().approved);
+
+        // Running the migrations again changes nothing.
+        crate::migrations::MigrateToLatest::<Test>::on_runtime_upgrade();
+        assert_eq!(SimpleGovernance::proposals(0), Some(closed));
+    });
+}
+
+#[test]
+fn votes_migrate_to_records() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(50);
+        Balances::make_free_balance_be(&1, 300);
+        Balances::make_free_balance_be(&2, 700);
+        StorageVersion::new(3).put::<SimpleGovernance>();
+        frame_support::storage::unhashed::put(&crate::Votes::<Test>::hashed_key_for(0, 1), &true);
+        crate::migrations::v3::VotesByAccount::<Test>::insert(
+            1,
+            0,
+            crate::migrations::v3::VoteRecord { vote: true, block: 5u64 },
+        );
+        // Voted before votes were indexed by account.
+        frame_support::storage::unhashed::put(&crate::Votes::<Test>::hashed_key_for(0, 2), &false);
+        // Counts that do not match the votes are replaced.
+        crate::ParticipationOf::<Test>::insert(1, VoteTally { for_votes: 3, against_votes: 1 });
+        crate::ParticipationOf::<Test>::insert(3, VoteTally { for_votes: 1, against_votes: 0 });
+
+        crate::migrations::MigrateToLatest::<Test>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<SimpleGovernance>(), 4);
+        let indexed = VoteRecord {
+            vote: true,
+            block: 5,
+            balance: 300,
+            conviction: Conviction::None,
+            reason: None,
+        };
+        assert_eq!(SimpleGovernance::votes(0, 1), Some(indexed.clone()));
+        assert_eq!(SimpleGovernance::votes_by_account(1, 0), Some(indexed));
+        let unindexed = VoteRecord {
+            vote: false,
+            block: 50,
+            balance: 700,
+            conviction: Conviction::None,
+            reason: None,
+        };
+        assert_eq!(SimpleGovernance::votes(0, 2), Some(unindexed.clone()));
+        assert_eq!(SimpleGovernance::votes_by_account(2, 0), Some(unindexed));
+
+        assert_eq!(
+            SimpleGovernance::participation_of(1),
+            VoteTally { for_votes: 1, against_votes: 0 }
+        );
+        assert_eq!(
+            SimpleGovernance::participation_of(2),
+            VoteTally { for_votes: 0, against_votes: 1 }
+        );
+        assert!(!crate::ParticipationOf::<Test>::contains_key(3));
+    });
+}
@@ -0,0 +1,75 @@
+//! Voting tracks and the decision curves used to evaluate proposals on them.
+//!
+//! Every proposal is submitted on a track. A track defines how much approval (the share of
+//! cast votes in favour) and support (the share of the eligible electorate in favour) a
+//! proposal needs. Both requirements are curves that fall over the voting period, so an
+//! uncontroversial proposal with broad support can pass early while a contested one needs the
+//! whole period.
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, PerThing, Perbill, RuntimeDebug};
+
+/// Identifier of a voting track.
+pub type TrackId = u16;
+
+/// A threshold that falls from `ceil` at the start of the voting period to `floor` at its end.
+#[derive(
+    Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum Curve {
+    /// Falls linearly from `ceil` to `floor`.
+    LinearDecreasing { ceil: Perbill, floor: Perbill },
+    /// Falls along `(1 - x) / (1 + factor * x)` from `ceil` to `floor`, dropping quickly at
+    /// first and flattening out towards the end. Larger factors drop faster.
+    Reciprocal { ceil: Perbill, floor: Perbill, factor: u32 },
+}
+
+impl Curve {
+    /// The threshold once `elapsed` of the voting period has passed.
+    pub fn threshold(&self, elapsed: Perbill) -> Perbill {
+        match *self {
+            Curve::LinearDecreasing { ceil, floor } => {
+                let drop = ceil.saturating_sub(floor);
+                ceil.saturating_sub(drop * elapsed)
+            },
+            Curve::Reciprocal { ceil, floor, factor } => {
+                let one = Perbill::ACCURACY as u64;
+                let x = elapsed.deconstruct() as u64;
+                let remaining = Perbill::from_rational(
+                    one.saturating_sub(x),
+                    one.saturating_add((factor as u64).saturating_mul(x)),
+                );
+                floor.saturating_add(ceil.saturating_sub(floor) * remaining)
+            },
+        }
+    }
+}
+
+/// Decision parameters of a single track.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct TrackInfo {
+    /// Human-readable name of the track.
+    pub name: &'static str,
+    /// Minimum share of cast votes that must be in favour.
+    pub min_approval: Curve,
+    /// Minimum share of the eligible electorate that must be in favour.
+    pub min_support: Curve,
+}
+
+/// The set of tracks proposals can be submitted on, configured by the runtime.
+pub trait TracksInfo {
+    /// All tracks, the first one being the default.
+    fn tracks() -> &'static [(TrackId, TrackInfo)];
+
+    /// The track used by proposals that do not name one.
+    fn default_track() -> Option<TrackId> {
+        Self::tracks().first().map(|(id, _)| *id)
+    }
+
+    /// The parameters of track `id`, if it exists.
+    fn info(id: TrackId) -> Option<&'static TrackInfo> {
+        Self::tracks().iter().find(|(track, _)| *track == id).map(|(_, info)| info)
+    }
+}
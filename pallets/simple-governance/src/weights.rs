@@ -32,6 +32,19 @@ pub trait WeightInfo {
     fn propose() -> Weight;
     fn vote() -> Weight;
     fn close_proposal() -> Weight;
+    fn propose_spend() -> Weight;
+    fn fast_track() -> Weight;
+    fn propose_upgrade() -> Weight;
+    fn propose_committee_change() -> Weight;
+    fn cancel() -> Weight;
+    fn propose_validator_change() -> Weight;
+    fn propose_call() -> Weight;
+    fn set_parameters() -> Weight;
+    fn clean_up_votes(n: u32, ) -> Weight;
+    fn vote_with_reason() -> Weight;
+    fn vote_signed() -> Weight;
+    fn vote_many(n: u32, ) -> Weight;
+    fn close_many(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_simple_governance using the Substrate node and recommended hardware.
@@ -41,52 +54,383 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
     /// Storage: System Account (r:1 w:0)
     /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
     /// Storage: SimpleGovernance Proposals (r:0 w:1)
     /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
     /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
     /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
     fn propose() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `76`
+        //  Measured:  `478`
         //  Estimated: `3593`
-        // Minimum execution time: 15_000_000 picoseconds.
-        Weight::from_parts(16_000_000, 3593)
-            .saturating_add(T::DbWeight::get().reads(2_u64))
-            .saturating_add(T::DbWeight::get().writes(3_u64))
+        // Minimum execution time: 22_000_000 picoseconds.
+        Weight::from_parts(23_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
     }
     
-    /// Storage: SimpleGovernance Proposals (r:1 w:0)
-    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:1 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(316), added: 2791, mode: MaxEncodedLen)
     /// Storage: System Account (r:1 w:0)
     /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
     /// Storage: SimpleGovernance Votes (r:1 w:1)
-    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
     /// Storage: SimpleGovernance VoteTallies (r:1 w:1)
     /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VotesByAccount (r:0 w:1)
+    /// Proof: SimpleGovernance VotesByAccount (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ParticipationOf (r:1 w:1)
+    /// Proof: SimpleGovernance ParticipationOf (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance FreeVotes (r:1 w:1)
+    /// Proof: SimpleGovernance FreeVotes (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
     fn vote() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `312`
-        //  Estimated: `3777`
-        // Minimum execution time: 18_000_000 picoseconds.
-        Weight::from_parts(19_000_000, 3777)
-            .saturating_add(T::DbWeight::get().reads(4_u64))
-            .saturating_add(T::DbWeight::get().writes(2_u64))
+        //  Measured:  `316`
+        //  Estimated: `3781`
+        // Minimum execution time: 27_000_000 picoseconds.
+        Weight::from_parts(28_000_000, 3781)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
     }
     
     /// Storage: SimpleGovernance Proposals (r:1 w:1)
     /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
     /// Storage: System Account (r:1 w:0)
     /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
     /// Storage: SimpleGovernance VoteTallies (r:1 w:0)
     /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:1 w:0)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: Vesting Vesting (r:1 w:1)
+    /// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: Balances Freezes (r:1 w:0)
+    /// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
     fn close_proposal() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `312`
-        //  Estimated: `3777`
-        // Minimum execution time: 12_000_000 picoseconds.
-        Weight::from_parts(13_000_000, 3777)
-            .saturating_add(T::DbWeight::get().reads(3_u64))
+        //  Measured:  `1126`
+        //  Estimated: `4764`
+        // Minimum execution time: 71_000_000 picoseconds.
+        Weight::from_parts(73_000_000, 4764)
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance NextProposalId (r:1 w:1)
+    /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:0 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:0 w:1)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn propose_spend() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `478`
+        //  Estimated: `3593`
+        // Minimum execution time: 25_000_000 picoseconds.
+        Weight::from_parts(26_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance Proposals (r:1 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    fn fast_track() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `245`
+        //  Estimated: `3783`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3783)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    
+    /// Storage: SimpleGovernance NextProposalId (r:1 w:1)
+    /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:0 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:0 w:1)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn propose_upgrade() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `478`
+        //  Estimated: `3593`
+        // Minimum execution time: 24_000_000 picoseconds.
+        Weight::from_parts(25_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance NextProposalId (r:1 w:1)
+    /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:0 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:0 w:1)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn propose_committee_change() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `478`
+        //  Estimated: `3593`
+        // Minimum execution time: 23_000_000 picoseconds.
+        Weight::from_parts(24_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance Proposals (r:1 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:1 w:0)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    fn cancel() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `412`
+        //  Estimated: `3783`
+        // Minimum execution time: 16_000_000 picoseconds.
+        Weight::from_parts(17_000_000, 3783)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    
+    /// Storage: SimpleGovernance NextProposalId (r:1 w:1)
+    /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:0 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:0 w:1)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn propose_validator_change() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `478`
+        //  Estimated: `3593`
+        // Minimum execution time: 23_000_000 picoseconds.
+        Weight::from_parts(24_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance NextProposalId (r:1 w:1)
+    /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:0 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:0 w:1)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn propose_call() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `478`
+        //  Estimated: `3593`
+        // Minimum execution time: 25_000_000 picoseconds.
+        Weight::from_parts(27_000_000, 3593)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance Parameters (r:0 w:1)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn set_parameters() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 6_000_000 picoseconds.
+        Weight::from_parts(7_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    
+    /// Storage: SimpleGovernance Votes (r:n w:n)
+    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VotesByAccount (r:0 w:n)
+    /// Proof: SimpleGovernance VotesByAccount (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 1000]`.
+    fn clean_up_votes(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `105 + n * (60 ±0)`
+        //  Estimated: `990 + n * (2695 ±0)`
+        // Minimum execution time: 4_000_000 picoseconds.
+        Weight::from_parts(4_500_000, 990)
+            // Standard Error: 1_500
+            .saturating_add(Weight::from_parts(1_650_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2695).saturating_mul(n.into()))
+    }
+    
+    /// Storage: SimpleGovernance Proposals (r:1 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(316), added: 2791, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Votes (r:1 w:1)
+    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:1 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VotesByAccount (r:0 w:1)
+    /// Proof: SimpleGovernance VotesByAccount (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ParticipationOf (r:1 w:1)
+    /// Proof: SimpleGovernance ParticipationOf (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    fn vote_with_reason() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `316`
+        //  Estimated: `3781`
+        // Minimum execution time: 27_000_000 picoseconds.
+        Weight::from_parts(28_000_000, 3781)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    
+    /// Storage: System BlockHash (r:1 w:0)
+    /// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance BallotNonces (r:1 w:1)
+    /// Proof: SimpleGovernance BallotNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:1 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(316), added: 2791, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Votes (r:1 w:1)
+    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:1 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VotesByAccount (r:0 w:1)
+    /// Proof: SimpleGovernance VotesByAccount (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ParticipationOf (r:1 w:1)
+    /// Proof: SimpleGovernance ParticipationOf (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:1 w:0)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    fn vote_signed() -> Weight {
+        // Not benchmarked yet: estimated from `vote` plus verifying the ballot signature, with
+        // the storage accesses listed above. Regenerate with `benchmark pallet`.
+        Weight::from_parts(76_000_000, 3781)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(6_u64))
+    }
+    
+    /// Storage: SimpleGovernance Proposals (r:n w:n)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(316), added: 2791, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Votes (r:n w:n)
+    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: System Account (r:n w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:n w:n)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VotesByAccount (r:0 w:n)
+    /// Proof: SimpleGovernance VotesByAccount (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ParticipationOf (r:n w:n)
+    /// Proof: SimpleGovernance ParticipationOf (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:n w:0)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 16]`.
+    fn vote_many(n: u32, ) -> Weight {
+        // Not benchmarked yet: estimated as one `vote` per item, with the storage accesses
+        // listed above. Regenerate with `benchmark pallet`.
+        Weight::from_parts(5_000_000, 0)
+            .saturating_add(Weight::from_parts(28_000_000, 3781).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+    }
+    
+    /// Storage: SimpleGovernance Proposals (r:n w:n)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+    /// Storage: System Account (r:2n w:2n)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:n w:n)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:n w:n)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:n w:0)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:n w:0)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: Vesting Vesting (r:n w:n)
+    /// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:n w:n)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: Balances Freezes (r:n w:0)
+    /// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance EnactedProposals (r:1 w:1)
+    /// Proof: SimpleGovernance EnactedProposals (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 16]`.
+    fn close_many(n: u32, ) -> Weight {
+        // Not benchmarked yet: estimated as one `close_proposal` of a vested spend per item,
+        // with the storage accesses listed above. Regenerate with `benchmark pallet`.
+        Weight::from_parts(5_000_000, 499)
+            .saturating_add(Weight::from_parts(73_000_000, 4764).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
             .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
     }
 }
 
@@ -96,51 +440,382 @@ impl WeightInfo for () {
     /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
     /// Storage: System Account (r:1 w:0)
     /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
     /// Storage: SimpleGovernance Proposals (r:0 w:1)
     /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
     /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
     /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
     fn propose() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `76`
+        //  Measured:  `478`
         //  Estimated: `3593`
-        // Minimum execution time: 15_000_000 picoseconds.
-        Weight::from_parts(16_000_000, 3593)
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(3_u64))
+        // Minimum execution time: 22_000_000 picoseconds.
+        Weight::from_parts(23_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
     }
     
-    /// Storage: SimpleGovernance Proposals (r:1 w:0)
-    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:1 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(316), added: 2791, mode: MaxEncodedLen)
     /// Storage: System Account (r:1 w:0)
     /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
     /// Storage: SimpleGovernance Votes (r:1 w:1)
-    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
     /// Storage: SimpleGovernance VoteTallies (r:1 w:1)
     /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VotesByAccount (r:0 w:1)
+    /// Proof: SimpleGovernance VotesByAccount (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ParticipationOf (r:1 w:1)
+    /// Proof: SimpleGovernance ParticipationOf (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance FreeVotes (r:1 w:1)
+    /// Proof: SimpleGovernance FreeVotes (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
     fn vote() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `312`
-        //  Estimated: `3777`
-        // Minimum execution time: 18_000_000 picoseconds.
-        Weight::from_parts(19_000_000, 3777)
-            .saturating_add(RocksDbWeight::get().reads(4_u64))
-            .saturating_add(RocksDbWeight::get().writes(2_u64))
+        //  Measured:  `316`
+        //  Estimated: `3781`
+        // Minimum execution time: 27_000_000 picoseconds.
+        Weight::from_parts(28_000_000, 3781)
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
     }
     
     /// Storage: SimpleGovernance Proposals (r:1 w:1)
     /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
     /// Storage: System Account (r:1 w:0)
     /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
     /// Storage: SimpleGovernance VoteTallies (r:1 w:0)
     /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:1 w:0)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: Vesting Vesting (r:1 w:1)
+    /// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:1 w:1)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: Balances Freezes (r:1 w:0)
+    /// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
     fn close_proposal() -> Weight {
         // Proof Size summary in bytes:
-        //  Measured:  `312`
-        //  Estimated: `3777`
-        // Minimum execution time: 12_000_000 picoseconds.
-        Weight::from_parts(13_000_000, 3777)
-            .saturating_add(RocksDbWeight::get().reads(3_u64))
+        //  Measured:  `1126`
+        //  Estimated: `4764`
+        // Minimum execution time: 71_000_000 picoseconds.
+        Weight::from_parts(73_000_000, 4764)
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance NextProposalId (r:1 w:1)
+    /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:0 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:0 w:1)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn propose_spend() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `478`
+        //  Estimated: `3593`
+        // Minimum execution time: 25_000_000 picoseconds.
+        Weight::from_parts(26_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance Proposals (r:1 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    fn fast_track() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `245`
+        //  Estimated: `3783`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3783)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    
+    /// Storage: SimpleGovernance NextProposalId (r:1 w:1)
+    /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:0 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:0 w:1)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn propose_upgrade() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `478`
+        //  Estimated: `3593`
+        // Minimum execution time: 24_000_000 picoseconds.
+        Weight::from_parts(25_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance NextProposalId (r:1 w:1)
+    /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:0 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:0 w:1)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn propose_committee_change() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `478`
+        //  Estimated: `3593`
+        // Minimum execution time: 23_000_000 picoseconds.
+        Weight::from_parts(24_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance Proposals (r:1 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:1 w:0)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    fn cancel() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `412`
+        //  Estimated: `3783`
+        // Minimum execution time: 16_000_000 picoseconds.
+        Weight::from_parts(17_000_000, 3783)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    
+    /// Storage: SimpleGovernance NextProposalId (r:1 w:1)
+    /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:0 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:0 w:1)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn propose_validator_change() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `478`
+        //  Estimated: `3593`
+        // Minimum execution time: 23_000_000 picoseconds.
+        Weight::from_parts(24_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance NextProposalId (r:1 w:1)
+    /// Proof: SimpleGovernance NextProposalId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance LastProposalBlock (r:1 w:1)
+    /// Proof: SimpleGovernance LastProposalBlock (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:1 w:1)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:0 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(318), added: 2793, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:0 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:0 w:1)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Parameters (r:1 w:0)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn propose_call() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `478`
+        //  Estimated: `3593`
+        // Minimum execution time: 25_000_000 picoseconds.
+        Weight::from_parts(27_000_000, 3593)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+    }
+    
+    /// Storage: SimpleGovernance Parameters (r:0 w:1)
+    /// Proof: SimpleGovernance Parameters (max_values: Some(1), max_size: Some(12), added: 507, mode: MaxEncodedLen)
+    fn set_parameters() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `0`
+        //  Estimated: `0`
+        // Minimum execution time: 6_000_000 picoseconds.
+        Weight::from_parts(7_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    
+    /// Storage: SimpleGovernance Votes (r:n w:n)
+    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VotesByAccount (r:0 w:n)
+    /// Proof: SimpleGovernance VotesByAccount (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 1000]`.
+    fn clean_up_votes(n: u32, ) -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `105 + n * (60 ±0)`
+        //  Estimated: `990 + n * (2695 ±0)`
+        // Minimum execution time: 4_000_000 picoseconds.
+        Weight::from_parts(4_500_000, 990)
+            // Standard Error: 1_500
+            .saturating_add(Weight::from_parts(1_650_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 2695).saturating_mul(n.into()))
+    }
+    
+    /// Storage: SimpleGovernance Proposals (r:1 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(316), added: 2791, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Votes (r:1 w:1)
+    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:1 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VotesByAccount (r:0 w:1)
+    /// Proof: SimpleGovernance VotesByAccount (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ParticipationOf (r:1 w:1)
+    /// Proof: SimpleGovernance ParticipationOf (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    fn vote_with_reason() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `316`
+        //  Estimated: `3781`
+        // Minimum execution time: 27_000_000 picoseconds.
+        Weight::from_parts(28_000_000, 3781)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    
+    /// Storage: System BlockHash (r:1 w:0)
+    /// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance BallotNonces (r:1 w:1)
+    /// Proof: SimpleGovernance BallotNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Proposals (r:1 w:1)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(316), added: 2791, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Votes (r:1 w:1)
+    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: System Account (r:1 w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:1 w:1)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VotesByAccount (r:0 w:1)
+    /// Proof: SimpleGovernance VotesByAccount (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ParticipationOf (r:1 w:1)
+    /// Proof: SimpleGovernance ParticipationOf (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:1 w:0)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    fn vote_signed() -> Weight {
+        // Not benchmarked yet: estimated from `vote` plus verifying the ballot signature, with
+        // the storage accesses listed above. Regenerate with `benchmark pallet`.
+        Weight::from_parts(76_000_000, 3781)
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(6_u64))
+    }
+    
+    /// Storage: SimpleGovernance Proposals (r:n w:n)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(316), added: 2791, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance Votes (r:n w:n)
+    /// Proof: SimpleGovernance Votes (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: System Account (r:n w:0)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:n w:n)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VotesByAccount (r:0 w:n)
+    /// Proof: SimpleGovernance VotesByAccount (max_values: None, max_size: Some(220), added: 2695, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ParticipationOf (r:n w:n)
+    /// Proof: SimpleGovernance ParticipationOf (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:n w:0)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 16]`.
+    fn vote_many(n: u32, ) -> Weight {
+        // Not benchmarked yet: estimated as one `vote` per item, with the storage accesses
+        // listed above. Regenerate with `benchmark pallet`.
+        Weight::from_parts(5_000_000, 0)
+            .saturating_add(Weight::from_parts(28_000_000, 3781).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+    }
+    
+    /// Storage: SimpleGovernance Proposals (r:n w:n)
+    /// Proof: SimpleGovernance Proposals (max_values: None, max_size: Some(312), added: 2787, mode: MaxEncodedLen)
+    /// Storage: System Account (r:2n w:2n)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposals (r:n w:n)
+    /// Proof: SimpleGovernance ActiveProposals (max_values: Some(1), max_size: Some(402), added: 897, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ActiveProposalsOf (r:n w:n)
+    /// Proof: SimpleGovernance ActiveProposalsOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance VoteTallies (r:n w:0)
+    /// Proof: SimpleGovernance VoteTallies (max_values: None, max_size: Some(40), added: 2515, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance ProposalActions (r:n w:0)
+    /// Proof: SimpleGovernance ProposalActions (max_values: None, max_size: Some(1027), added: 3502, mode: MaxEncodedLen)
+    /// Storage: Vesting Vesting (r:n w:n)
+    /// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+    /// Storage: Balances Locks (r:n w:n)
+    /// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
+    /// Storage: Balances Freezes (r:n w:0)
+    /// Proof: Balances Freezes (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+    /// Storage: SimpleGovernance EnactedProposals (r:1 w:1)
+    /// Proof: SimpleGovernance EnactedProposals (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 16]`.
+    fn close_many(n: u32, ) -> Weight {
+        // Not benchmarked yet: estimated as one `close_proposal` of a vested spend per item,
+        // with the storage accesses listed above. Regenerate with `benchmark pallet`.
+        Weight::from_parts(5_000_000, 499)
+            .saturating_add(Weight::from_parts(73_000_000, 4764).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
             .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((7_u64).saturating_mul(n.into())))
     }
 }
\ No newline at end of file
@@ -4,31 +4,54 @@ use super::*;
 
 #[allow(unused)]
 use crate::Pallet as Template;
+use alloc::vec;
 use frame_benchmarking::v2::*;
-use frame_system::RawOrigin;
+use frame_support::{traits::EnsureOrigin, BoundedVec};
+
+/// A key of the maximum length whose history is full, so recording a change drops the oldest.
+fn full_key<T: Config>() -> ConfigKey<T> {
+	let key: ConfigKey<T> = vec![0u8; T::MaxKeyLength::get() as usize].try_into().unwrap();
+	let proposal_id = T::UpdateOrigin::try_successful_origin()
+		.and_then(|origin| T::UpdateOrigin::try_origin(origin).map_err(|_| ()))
+		.unwrap();
+	Entries::<T>::insert(&key, max_value());
+	let change = ConfigChange { value: Some(max_value()), proposal_id, block: 0u32.into() };
+	let history = vec![change; T::MaxHistory::get() as usize];
+	History::<T>::insert(&key, BoundedVec::truncate_from(history));
+	key
+}
+
+/// The longest value.
+fn max_value() -> ConfigValue {
+	ConfigValue::Text(vec![0u8; MAX_TEXT_LENGTH as usize].try_into().unwrap())
+}
 
 #[benchmarks]
 mod benchmarks {
 	use super::*;
 
 	#[benchmark]
-	fn do_something() {
-		let value = 100u32;
-		let caller: T::AccountId = whitelisted_caller();
+	fn set_config() -> Result<(), BenchmarkError> {
+		let key = full_key::<T>();
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		#[extrinsic_call]
-		do_something(RawOrigin::Signed(caller), value);
+		set_config(origin as T::RuntimeOrigin, key.to_vec(), max_value());
 
-		assert_eq!(Something::<T>::get(), Some(value));
+		assert_eq!(Entries::<T>::get(&key), Some(max_value()));
+		Ok(())
 	}
 
 	#[benchmark]
-	fn cause_error() {
-		Something::<T>::put(100u32);
-		let caller: T::AccountId = whitelisted_caller();
+	fn remove_config() -> Result<(), BenchmarkError> {
+		let key = full_key::<T>();
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		#[extrinsic_call]
-		cause_error(RawOrigin::Signed(caller));
+		remove_config(origin as T::RuntimeOrigin, key.to_vec());
 
-		assert_eq!(Something::<T>::get(), Some(101u32));
+		assert_eq!(Entries::<T>::get(&key), None);
+		Ok(())
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
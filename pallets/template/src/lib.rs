@@ -1,18 +1,20 @@
 //! # Template Pallet
 //!
-//! A pallet with minimal functionality to help developers understand the essential components of
-//! writing a FRAME pallet. It is typically used in beginner tutorials or in Substrate template
-//! nodes as a starting point for creating a new pallet and **not meant to be used in production**.
+//! A governance-controlled configuration registry that application pallets read their settings
+//! from. It started out as the template pallet of the Substrate node template and keeps its
+//! annotated layout as a starting point for writing new pallets.
 //!
 //! ## Overview
 //!
-//! This template pallet contains basic examples of:
-//! - declaring a storage item that stores a single `u32` value
-//! - declaring and using events
-//! - declaring and using errors
-//! - a dispatchable function that allows a user to set a new value to storage and emits an event
-//!   upon success
-//! - another dispatchable function that causes a custom error to be thrown
+//! The registry stores typed values under byte-string keys:
+//! - values are booleans, integers or short texts ([`ConfigValue`]), and a key keeps the type of
+//!   its first value until it is removed
+//! - values are only written by [`Config::UpdateOrigin`], e.g. a proposal passed on a governance
+//!   track, which yields the ID of the proposal that approved the change
+//! - the last [`Config::MaxHistory`] changes of every key are kept, each with the proposal that
+//!   made it and the block it was made in
+//! - other pallets read values through [`Pallet::value`] and the typed accessors of
+//!   [`ConfigValue`], and clients through the [`runtime_api::ConfigRegistryApi`] runtime API
 //!
 //! Each pallet section is annotated with an attribute using the `#[pallet::...]` procedural macro.
 //! This macro generates the necessary code for a pallet to be aggregated into a FRAME runtime.
@@ -39,6 +41,8 @@
 // We make sure this pallet uses `no_std` for compiling to Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
@@ -60,17 +64,29 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+// The runtime API clients read the registry through.
+pub mod runtime_api;
+
+// Migrations of the pallet's storage between versions of its layout.
+pub mod migrations;
+
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet]
 pub mod pallet {
 	// Import various useful types required by all FRAME pallets.
 	use super::*;
+	use alloc::vec::Vec;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// The current version of the layout of the pallet's storage. Version 1 dropped the
+	/// template's `Something` value, see [`migrations::v1`].
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	// The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
 	// (`Call`s) in this pallet.
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// The pallet's configuration trait.
@@ -84,14 +100,40 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// A type representing the weights required by the dispatchables of this pallet.
 		type WeightInfo: WeightInfo;
+		/// Identifier of the governance proposal that approved a change.
+		type ProposalId: Parameter + MaxEncodedLen + Copy;
+		/// The origin allowed to change the registry, e.g. a proposal passed on a governance
+		/// track, yielding the ID of that proposal.
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::ProposalId>;
+		/// Maximum length of a key.
+		#[pallet::constant]
+		type MaxKeyLength: Get<u32>;
+		/// Number of past changes kept per key. Older changes are dropped.
+		#[pallet::constant]
+		type MaxHistory: Get<u32>;
 	}
 
-	/// A storage item for this pallet.
+	/// A key of the registry.
+	pub type ConfigKey<T> = BoundedVec<u8, <T as Config>::MaxKeyLength>;
+
+	/// A recorded change of a key in a runtime.
+	pub type ConfigChangeOf<T> = ConfigChange<<T as Config>::ProposalId, BlockNumberFor<T>>;
+
+	/// The current value of every key.
 	///
-	/// In this template, we are declaring a storage item called `Something` that stores a single
-	/// `u32` value. Learn more about runtime storage here: <https://docs.substrate.io/build/runtime-storage/>
+	/// Learn more about runtime storage here: <https://docs.substrate.io/build/runtime-storage/>
 	#[pallet::storage]
-	pub type Something<T> = StorageValue<_, u32>;
+	pub type Entries<T: Config> = StorageMap<_, Blake2_128Concat, ConfigKey<T>, ConfigValue>;
+
+	/// The last `MaxHistory` changes of every key, oldest first, including removals.
+	#[pallet::storage]
+	pub type History<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ConfigKey<T>,
+		BoundedVec<ConfigChangeOf<T>, T::MaxHistory>,
+		ValueQuery,
+	>;
 
 	/// Events that functions in this pallet can emit.
 	///
@@ -106,12 +148,21 @@ pub mod pallet {
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// A user has successfully set a new value.
-		SomethingStored {
-			/// The new value set.
-			something: u32,
-			/// The account who set the new value.
-			who: T::AccountId,
+		/// A key was set to a new value.
+		ConfigSet {
+			/// The key that was set.
+			key: ConfigKey<T>,
+			/// The new value.
+			value: ConfigValue,
+			/// The proposal that approved the change.
+			proposal_id: T::ProposalId,
+		},
+		/// A key was removed.
+		ConfigRemoved {
+			/// The key that was removed.
+			key: ConfigKey<T>,
+			/// The proposal that approved the removal.
+			proposal_id: T::ProposalId,
 		},
 	}
 
@@ -125,10 +176,12 @@ pub mod pallet {
 	/// information.
 	#[pallet::error]
 	pub enum Error<T> {
-		/// The value retrieved was `None` as no value was previously set.
-		NoneValue,
-		/// There was an attempt to increment the value in storage over `u32::MAX`.
-		StorageOverflow,
+		/// The key is longer than `MaxKeyLength`.
+		KeyTooLong,
+		/// The key is not set.
+		UnknownKey,
+		/// The value's type differs from the type of the key's current value.
+		ValueTypeMismatch,
 	}
 
 	/// The pallet's dispatchable functions ([`Call`]s).
@@ -145,58 +198,195 @@ pub mod pallet {
 	/// The [`weight`] macro is used to assign a weight to each call.
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// An example dispatchable that takes a single u32 value as a parameter, writes the value
-		/// to storage and emits an event.
+		/// Set `key` to `value`, recording the change in the key's history.
+		///
+		/// It checks that the _origin_ for this call is [`Config::UpdateOrigin`], e.g. a proposal
+		/// passed on a governance track, and returns a dispatch error if it isn't. Learn more
+		/// about origins here: <https://docs.substrate.io/build/origins/>
 		///
-		/// It checks that the _origin_ for this call is _Signed_ and returns a dispatch
-		/// error if it isn't. Learn more about origins here: <https://docs.substrate.io/build/origins/>
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::do_something())]
-		pub fn do_something(origin: OriginFor<T>, something: u32) -> DispatchResult {
-			// Check that the extrinsic was signed and get the signer.
-			let who = ensure_signed(origin)?;
+		/// ## Errors
+		///
+		/// - If the key is longer than `MaxKeyLength` ([`Error::KeyTooLong`])
+		/// - If the key is set to a value of another type ([`Error::ValueTypeMismatch`])
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::set_config())]
+		pub fn set_config(
+			origin: OriginFor<T>,
+			key: Vec<u8>,
+			value: ConfigValue,
+		) -> DispatchResult {
+			let proposal_id = T::UpdateOrigin::ensure_origin(origin)?;
+			let key: ConfigKey<T> = key.try_into().map_err(|_| Error::<T>::KeyTooLong)?;
 
-			// Update storage.
-			Something::<T>::put(something);
+			// A key keeps its type until it is removed.
+			if let Some(current) = Entries::<T>::get(&key) {
+				ensure!(current.has_type_of(&value), Error::<T>::ValueTypeMismatch);
+			}
 
-			// Emit an event.
-			Self::deposit_event(Event::SomethingStored { something, who });
+			Entries::<T>::insert(&key, &value);
+			Self::record_change(&key, Some(value.clone()), proposal_id);
 
-			// Return a successful `DispatchResult`
+			Self::deposit_event(Event::ConfigSet { key, value, proposal_id });
 			Ok(())
 		}
 
-		/// An example dispatchable that may throw a custom error.
+		/// Remove `key`, recording the removal in the key's history.
 		///
-		/// It checks that the caller is a signed origin and reads the current value from the
-		/// `Something` storage item. If a current value exists, it is incremented by 1 and then
-		/// written back to storage.
+		/// It checks that the _origin_ for this call is [`Config::UpdateOrigin`]. Once removed,
+		/// the key can be set to a value of any type.
 		///
 		/// ## Errors
 		///
-		/// The function will return an error under the following conditions:
-		///
-		/// - If no value has been set ([`Error::NoneValue`])
-		/// - If incrementing the value in storage causes an arithmetic overflow
-		///   ([`Error::StorageOverflow`])
-		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::cause_error())]
-		pub fn cause_error(origin: OriginFor<T>) -> DispatchResult {
-			let _who = ensure_signed(origin)?;
-
-			// Read a value from storage.
-			match Something::<T>::get() {
-				// Return an error if the value has not been set.
-				None => Err(Error::<T>::NoneValue.into()),
-				Some(old) => {
-					// Increment the value read from storage. This will cause an error in the event
-					// of overflow.
-					let new = old.checked_add(1).ok_or(Error::<T>::StorageOverflow)?;
-					// Update the value in storage with the incremented result.
-					Something::<T>::put(new);
-					Ok(())
-				},
+		/// - If the key is longer than `MaxKeyLength` ([`Error::KeyTooLong`])
+		/// - If the key is not set ([`Error::UnknownKey`])
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::remove_config())]
+		pub fn remove_config(origin: OriginFor<T>, key: Vec<u8>) -> DispatchResult {
+			let proposal_id = T::UpdateOrigin::ensure_origin(origin)?;
+			let key: ConfigKey<T> = key.try_into().map_err(|_| Error::<T>::KeyTooLong)?;
+
+			Entries::<T>::take(&key).ok_or(Error::<T>::UnknownKey)?;
+			Self::record_change(&key, None, proposal_id);
+
+			Self::deposit_event(Event::ConfigRemoved { key, proposal_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The current value of `key`, if it is set.
+		pub fn value(key: &[u8]) -> Option<ConfigValue> {
+			let key: ConfigKey<T> = key.to_vec().try_into().ok()?;
+			Entries::<T>::get(key)
+		}
+
+		/// All keys and their current values.
+		pub fn entries() -> Vec<(Vec<u8>, ConfigValue)> {
+			Entries::<T>::iter().map(|(key, value)| (key.into_inner(), value)).collect()
+		}
+
+		/// The recorded changes of `key`, oldest first.
+		pub fn history(key: &[u8]) -> Vec<ConfigChangeOf<T>> {
+			let Ok(key) = ConfigKey::<T>::try_from(key.to_vec()) else {
+				return Vec::new();
+			};
+			History::<T>::get(key).into_inner()
+		}
+
+		/// Append a change to the history of `key`, dropping the oldest one if it is full.
+		fn record_change(
+			key: &ConfigKey<T>,
+			value: Option<ConfigValue>,
+			proposal_id: T::ProposalId,
+		) {
+			let change = ConfigChange {
+				value,
+				proposal_id,
+				block: frame_system::Pallet::<T>::block_number(),
+			};
+			History::<T>::mutate(key, |history| {
+				if history.is_full() && !history.is_empty() {
+					history.remove(0);
+				}
+				// Cannot fail once there is room, and only fails if `MaxHistory` is zero.
+				let _ = history.try_push(change);
+			});
+		}
+	}
+
+	/// Longest text a value can hold.
+	pub const MAX_TEXT_LENGTH: u32 = 256;
+
+	/// A typed value of the registry.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub enum ConfigValue {
+		/// A flag.
+		Bool(bool),
+		/// A 32-bit unsigned integer.
+		U32(u32),
+		/// A 64-bit unsigned integer.
+		U64(u64),
+		/// A 128-bit unsigned integer, e.g. a balance.
+		U128(u128),
+		/// A text of up to `MAX_TEXT_LENGTH` bytes.
+		Text(BoundedVec<u8, ConstU32<MAX_TEXT_LENGTH>>),
+	}
+
+	impl ConfigValue {
+		/// Whether `other` is of the same type as this value.
+		pub fn has_type_of(&self, other: &ConfigValue) -> bool {
+			core::mem::discriminant(self) == core::mem::discriminant(other)
+		}
+
+		/// The value if it is a flag.
+		pub fn as_bool(&self) -> Option<bool> {
+			match self {
+				ConfigValue::Bool(value) => Some(*value),
+				_ => None,
+			}
+		}
+
+		/// The value if it is a 32-bit integer.
+		pub fn as_u32(&self) -> Option<u32> {
+			match self {
+				ConfigValue::U32(value) => Some(*value),
+				_ => None,
+			}
+		}
+
+		/// The value if it is a 64-bit integer.
+		pub fn as_u64(&self) -> Option<u64> {
+			match self {
+				ConfigValue::U64(value) => Some(*value),
+				_ => None,
+			}
+		}
+
+		/// The value if it is a 128-bit integer.
+		pub fn as_u128(&self) -> Option<u128> {
+			match self {
+				ConfigValue::U128(value) => Some(*value),
+				_ => None,
+			}
+		}
+
+		/// The value if it is a text.
+		pub fn as_text(&self) -> Option<&[u8]> {
+			match self {
+				ConfigValue::Text(value) => Some(&value[..]),
+				_ => None,
 			}
 		}
 	}
+
+	/// A recorded change of a key.
+	#[derive(
+		Encode,
+		Decode,
+		DecodeWithMemTracking,
+		Clone,
+		PartialEq,
+		Eq,
+		RuntimeDebug,
+		TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub struct ConfigChange<ProposalId, BlockNumber> {
+		/// The value the key was set to, or `None` if it was removed.
+		pub value: Option<ConfigValue>,
+		/// The proposal that approved the change.
+		pub proposal_id: ProposalId,
+		/// The block the change was made in.
+		pub block: BlockNumber,
+	}
 }
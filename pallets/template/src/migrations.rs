@@ -0,0 +1,48 @@
+//! Storage migrations of the registry.
+
+use crate::pallet::{Config, Pallet};
+use core::marker::PhantomData;
+use frame_support::{
+	migrations::VersionedMigration,
+	storage_alias,
+	traits::{Get, UncheckedOnRuntimeUpgrade},
+	weights::Weight,
+};
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+
+/// Version 1 removes the `Something` value of the template pallet the registry grew out of.
+pub mod v1 {
+	use super::*;
+
+	/// The number stored by the template pallet's `do_something` call.
+	#[storage_alias]
+	pub type Something<T: Config> = StorageValue<Pallet<T>, u32>;
+
+	/// Removes `Something`, which nothing reads any more.
+	pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			Something::<T>::kill();
+			T::DbWeight::get().writes(1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			frame_support::ensure!(!Something::<T>::exists(), "`Something` was not removed");
+			Ok(())
+		}
+	}
+
+	/// Migrates from version 0 to 1.
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		UncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
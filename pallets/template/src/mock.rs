@@ -1,5 +1,6 @@
 use crate as pallet_template;
-use frame_support::derive_impl;
+use frame_support::{derive_impl, traits::ConstU32};
+use frame_system::EnsureSigned;
 use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -38,6 +39,11 @@ impl frame_system::Config for Test {
 impl pallet_template::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type ProposalId = u64;
+	// The signing account stands in for the ID of the approving proposal.
+	type UpdateOrigin = EnsureSigned<u64>;
+	type MaxKeyLength = ConstU32<16>;
+	type MaxHistory = ConstU32<3>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -0,0 +1,23 @@
+//! Runtime API for reading the configuration registry.
+
+use crate::{ConfigChange, ConfigValue};
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// Read access to the configuration registry.
+	pub trait ConfigRegistryApi<ProposalId, BlockNumber>
+	where
+		ProposalId: Codec,
+		BlockNumber: Codec,
+	{
+		/// The current value of `key`, if it is set.
+		fn value(key: Vec<u8>) -> Option<ConfigValue>;
+
+		/// All keys and their current values.
+		fn entries() -> Vec<(Vec<u8>, ConfigValue)>;
+
+		/// The recorded changes of `key`, oldest first.
+		fn history(key: Vec<u8>) -> Vec<ConfigChange<ProposalId, BlockNumber>>;
+	}
+}
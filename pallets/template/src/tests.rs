@@ -1,24 +1,133 @@
-use crate::{mock::*, Error, Event, Something};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, ConfigChange, ConfigValue, Entries, Error, Event, History};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{OnRuntimeUpgrade, StorageVersion},
+};
+use sp_runtime::DispatchError;
+
+fn key(key: &[u8]) -> crate::ConfigKey<Test> {
+	key.to_vec().try_into().unwrap()
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn set_config_works() {
 	new_test_ext().execute_with(|| {
 		// Go past genesis block so events get deposited
 		System::set_block_number(1);
-		// Dispatch a signed extrinsic.
-		assert_ok!(Template::do_something(RuntimeOrigin::signed(1), 42));
+		// Dispatch a call approved by proposal 7.
+		assert_ok!(Template::set_config(
+			RuntimeOrigin::signed(7),
+			b"fee".to_vec(),
+			ConfigValue::U32(42)
+		));
 		// Read pallet storage and assert an expected result.
-		assert_eq!(Something::<Test>::get(), Some(42));
+		assert_eq!(Entries::<Test>::get(key(b"fee")), Some(ConfigValue::U32(42)));
+		assert_eq!(Template::value(b"fee").and_then(|value| value.as_u32()), Some(42));
+		assert_eq!(Template::entries(), vec![(b"fee".to_vec(), ConfigValue::U32(42))]);
 		// Assert that the correct event was deposited
-		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
+		System::assert_last_event(
+			Event::ConfigSet { key: key(b"fee"), value: ConfigValue::U32(42), proposal_id: 7 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn set_config_requires_update_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Template::set_config(RuntimeOrigin::root(), b"fee".to_vec(), ConfigValue::U32(42)),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Template::remove_config(RuntimeOrigin::root(), b"fee".to_vec()),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn keys_keep_their_type_until_removed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Template::set_config(
+			RuntimeOrigin::signed(1),
+			b"open".to_vec(),
+			ConfigValue::Bool(true)
+		));
+		assert_noop!(
+			Template::set_config(RuntimeOrigin::signed(2), b"open".to_vec(), ConfigValue::U32(1)),
+			Error::<Test>::ValueTypeMismatch
+		);
+		assert_ok!(Template::set_config(
+			RuntimeOrigin::signed(2),
+			b"open".to_vec(),
+			ConfigValue::Bool(false)
+		));
+
+		assert_ok!(Template::remove_config(RuntimeOrigin::signed(3), b"open".to_vec()));
+		assert_eq!(Template::value(b"open"), None);
+		assert_ok!(Template::set_config(
+			RuntimeOrigin::signed(4),
+			b"open".to_vec(),
+			ConfigValue::U32(1)
+		));
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn invalid_keys_are_rejected() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
-		assert_noop!(Template::cause_error(RuntimeOrigin::signed(1)), Error::<Test>::NoneValue);
+		assert_noop!(
+			Template::set_config(RuntimeOrigin::signed(1), vec![0; 17], ConfigValue::Bool(true)),
+			Error::<Test>::KeyTooLong
+		);
+		assert_noop!(
+			Template::remove_config(RuntimeOrigin::signed(1), b"fee".to_vec()),
+			Error::<Test>::UnknownKey
+		);
+	});
+}
+
+#[test]
+fn history_records_proposals_and_drops_oldest_changes() {
+	new_test_ext().execute_with(|| {
+		for (block, proposal_id) in [(1, 10), (2, 11), (3, 12)] {
+			System::set_block_number(block);
+			assert_ok!(Template::set_config(
+				RuntimeOrigin::signed(proposal_id),
+				b"fee".to_vec(),
+				ConfigValue::U128(block as u128)
+			));
+		}
+		System::set_block_number(4);
+		assert_ok!(Template::remove_config(RuntimeOrigin::signed(13), b"fee".to_vec()));
+		System::assert_last_event(
+			Event::ConfigRemoved { key: key(b"fee"), proposal_id: 13 }.into(),
+		);
+
+		// Only the last `MaxHistory` changes are kept, oldest first.
+		assert_eq!(
+			Template::history(b"fee"),
+			vec![
+				ConfigChange { value: Some(ConfigValue::U128(2)), proposal_id: 11, block: 2 },
+				ConfigChange { value: Some(ConfigValue::U128(3)), proposal_id: 12, block: 3 },
+				ConfigChange { value: None, proposal_id: 13, block: 4 },
+			]
+		);
+		assert_eq!(History::<Test>::get(key(b"fee")).len(), 3);
+		assert!(Template::history(b"other").is_empty());
+	});
+}
+
+#[test]
+fn migration_removes_the_template_value() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Template>();
+		crate::migrations::v1::Something::<Test>::put(42);
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert!(!crate::migrations::v1::Something::<Test>::exists());
+		assert_eq!(StorageVersion::get::<Template>(), 1);
 	});
 }
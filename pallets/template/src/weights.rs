@@ -34,57 +34,67 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for pallet_template.
 pub trait WeightInfo {
-	fn do_something() -> Weight;
-	fn cause_error() -> Weight;
+	fn set_config() -> Weight;
+	fn remove_config() -> Weight;
 }
 
 /// Weights for pallet_template using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
-	/// Storage: Template Something (r:0 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
+	/// Storage: Template Entries (r:1 w:1)
+	/// Proof: Template Entries (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: Template History (r:1 w:1)
+	/// Proof: Template History (max_values: None, max_size: Some(4371), added: 6846, mode: MaxEncodedLen)
+	fn set_config() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `4512`
+		//  Estimated: `7836`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 7836)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: Template Something (r:1 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: Template Entries (r:1 w:1)
+	/// Proof: Template Entries (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: Template History (r:1 w:1)
+	/// Proof: Template History (max_values: None, max_size: Some(4371), added: 6846, mode: MaxEncodedLen)
+	fn remove_config() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+		//  Measured:  `4512`
+		//  Estimated: `7836`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 7836)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	/// Storage: Template Something (r:0 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn do_something() -> Weight {
+	/// Storage: Template Entries (r:1 w:1)
+	/// Proof: Template Entries (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: Template History (r:1 w:1)
+	/// Proof: Template History (max_values: None, max_size: Some(4371), added: 6846, mode: MaxEncodedLen)
+	fn set_config() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 8_000_000 picoseconds.
-		Weight::from_parts(9_000_000, 0)
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `4512`
+		//  Estimated: `7836`
+		// Minimum execution time: 31_000_000 picoseconds.
+		Weight::from_parts(33_000_000, 7836)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	/// Storage: Template Something (r:1 w:1)
-	/// Proof: Template Something (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
-	fn cause_error() -> Weight {
+	/// Storage: Template Entries (r:1 w:1)
+	/// Proof: Template Entries (max_values: None, max_size: Some(340), added: 2815, mode: MaxEncodedLen)
+	/// Storage: Template History (r:1 w:1)
+	/// Proof: Template History (max_values: None, max_size: Some(4371), added: 6846, mode: MaxEncodedLen)
+	fn remove_config() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `32`
-		//  Estimated: `1489`
-		// Minimum execution time: 6_000_000 picoseconds.
-		Weight::from_parts(6_000_000, 1489)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+		//  Measured:  `4512`
+		//  Estimated: `7836`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(31_000_000, 7836)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
@@ -0,0 +1,51 @@
+//! Benchmarking setup for pallet-validator-set
+
+use super::*;
+use crate::Pallet as ValidatorSet;
+use alloc::vec::Vec;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::EnsureOrigin, BoundedVec};
+
+/// Make `count` accounts the validators.
+fn set_validators<T: Config>(count: u32) -> Vec<T::AccountId> {
+    let validators: Vec<T::AccountId> = (0..count).map(|i| account("validator", i, 0)).collect();
+    let bounded: BoundedVec<_, T::MaxValidators> = validators.clone().try_into().unwrap();
+    Validators::<T>::put(bounded);
+    validators
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn add_validator() -> Result<(), BenchmarkError> {
+        set_validators::<T>(T::MaxValidators::get().saturating_sub(1));
+        let who: T::AccountId = account("candidate", 0, 0);
+        T::BenchmarkHelper::register(&who);
+        let origin =
+            T::AddRemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, who.clone());
+
+        assert!(ValidatorSet::<T>::validators().contains(&who));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn remove_validator() -> Result<(), BenchmarkError> {
+        let validators = set_validators::<T>(T::MaxValidators::get());
+        let who = validators.last().unwrap().clone();
+        let origin =
+            T::AddRemoveOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, who.clone());
+
+        assert!(!ValidatorSet::<T>::validators().contains(&who));
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(ValidatorSet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,321 @@
+//! # Validator Set Pallet
+//!
+//! Maintains the validators of a proof-of-authority chain for `pallet_session`, so the
+//! authorities of Aura and GRANDPA can change without a hard fork.
+//!
+//! ## Overview
+//!
+//! This pallet provides the following functionality:
+//! - `AddRemoveOrigin`, e.g. governance, adds and removes validators
+//! - A validator must have registered session keys before it can be added
+//! - The set never shrinks below `MinValidators` or grows beyond `MaxValidators`
+//! - As the session manager, the pallet hands changed validators to `pallet_session`, which
+//!   applies them from the next session on
+//! - As the offences pallet's handler, the pallet records validators reported for misbehaviour,
+//!   e.g. GRANDPA equivocations, so governance can decide whether to remove them
+//!
+//! ## Usage
+//!
+//! ### Adding a Validator
+//! ```ignore
+//! // Add account 4, which has called `Session::set_keys`
+//! ValidatorSet::add_validator(origin, 4)?;
+//! ```
+//!
+//! ### Removing a Validator
+//! ```ignore
+//! ValidatorSet::remove_validator(origin, 4)?;
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub use pallet::*;
+
+use alloc::vec::Vec;
+use frame_support::{traits::Contains, weights::Weight};
+use sp_runtime::Perbill;
+use sp_staking::{
+    offence::{OffenceDetails, OnOffenceHandler},
+    SessionIndex,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::{pallet_prelude::*, traits::ValidatorRegistration};
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Configuration trait for the validator set pallet.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching runtime event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+
+        /// Origin that can add and remove validators, e.g. governance.
+        type AddRemoveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Whether an account has registered session keys, e.g. `pallet_session`.
+        type ValidatorRegistration: ValidatorRegistration<Self::AccountId>;
+
+        /// The smallest number of validators the set can shrink to.
+        #[pallet::constant]
+        type MinValidators: Get<u32>;
+
+        /// The largest number of validators the set can grow to.
+        #[pallet::constant]
+        type MaxValidators: Get<u32>;
+
+        /// Registers session keys for benchmarked validators.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId>;
+    }
+
+    /// The validators, applied from the session after they last changed.
+    #[pallet::storage]
+    #[pallet::getter(fn validators)]
+    pub type Validators<T: Config> =
+        StorageValue<_, BoundedVec<T::AccountId, T::MaxValidators>, ValueQuery>;
+
+    /// Whether the validators changed since they were last handed to the session pallet.
+    #[pallet::storage]
+    #[pallet::getter(fn validators_changed)]
+    pub type ValidatorsChanged<T> = StorageValue<_, bool, ValueQuery>;
+
+    /// Validators reported for an offence, with the session of their last offence. Cleared
+    /// when the validator is removed.
+    #[pallet::storage]
+    #[pallet::getter(fn offenders)]
+    pub type Offenders<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SessionIndex, OptionQuery>;
+
+    /// Events emitted by the validator set pallet.
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A validator was added, effective from the next session.
+        ValidatorAdded { who: T::AccountId },
+
+        /// A validator was removed, effective from the next session.
+        ValidatorRemoved { who: T::AccountId },
+
+        /// The changed validators were handed to the session pallet for session
+        /// `session_index`.
+        ValidatorsPlanned { session_index: SessionIndex, count: u32 },
+
+        /// A validator was reported for an offence in session `session_index`.
+        OffenceReported { who: T::AccountId, session_index: SessionIndex },
+    }
+
+    /// Errors that can occur in the validator set pallet.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The account already is a validator.
+        AlreadyValidator,
+
+        /// The account is not a validator.
+        NotValidator,
+
+        /// The account has not registered session keys.
+        NoSessionKeys,
+
+        /// Removing the validator would leave fewer than `MinValidators` validators.
+        TooFewValidators,
+
+        /// Adding the validator would exceed `MaxValidators` validators.
+        TooManyValidators,
+    }
+
+    /// The pallet's callable functions.
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Add a validator, effective from the next session.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `AddRemoveOrigin`
+        /// - `who`: The account to add, which must have registered session keys
+        ///
+        /// Emits `ValidatorAdded` event on success.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::add_validator())]
+        pub fn add_validator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AddRemoveOrigin::ensure_origin(origin)?;
+
+            Self::do_add_validator(who)
+        }
+
+        /// Remove a validator, effective from the next session.
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `AddRemoveOrigin`
+        /// - `who`: The validator to remove
+        ///
+        /// Emits `ValidatorRemoved` event on success.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::remove_validator())]
+        pub fn remove_validator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+            T::AddRemoveOrigin::ensure_origin(origin)?;
+
+            Self::do_remove_validator(who)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Add `who` to the validators without checking the origin.
+        pub fn do_add_validator(who: T::AccountId) -> DispatchResult {
+            ensure!(T::ValidatorRegistration::is_registered(&who), Error::<T>::NoSessionKeys);
+
+            Validators::<T>::try_mutate(|validators| {
+                ensure!(!validators.contains(&who), Error::<T>::AlreadyValidator);
+                validators.try_push(who.clone()).map_err(|_| Error::<T>::TooManyValidators)?;
+                Ok::<_, DispatchError>(())
+            })?;
+            ValidatorsChanged::<T>::put(true);
+
+            Self::deposit_event(Event::ValidatorAdded { who });
+            Ok(())
+        }
+
+        /// Remove `who` from the validators without checking the origin.
+        pub fn do_remove_validator(who: T::AccountId) -> DispatchResult {
+            Validators::<T>::try_mutate(|validators| {
+                let index = validators
+                    .iter()
+                    .position(|validator| *validator == who)
+                    .ok_or(Error::<T>::NotValidator)?;
+                ensure!(
+                    validators.len() as u32 > T::MinValidators::get(),
+                    Error::<T>::TooFewValidators
+                );
+                validators.remove(index);
+                Ok::<_, DispatchError>(())
+            })?;
+            ValidatorsChanged::<T>::put(true);
+            Offenders::<T>::remove(&who);
+
+            Self::deposit_event(Event::ValidatorRemoved { who });
+            Ok(())
+        }
+    }
+
+    /// Genesis configuration for the pallet.
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// The validators of the first session. They must match the accounts given session
+        /// keys in the session pallet's genesis.
+        pub initial_validators: Vec<T::AccountId>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            let mut validators = self.initial_validators.clone();
+            validators.sort();
+            validators.dedup();
+            assert_eq!(
+                validators.len(),
+                self.initial_validators.len(),
+                "duplicate initial validators"
+            );
+            assert!(
+                validators.len() as u32 >= T::MinValidators::get(),
+                "fewer initial validators than MinValidators"
+            );
+
+            let validators: BoundedVec<_, T::MaxValidators> = self
+                .initial_validators
+                .clone()
+                .try_into()
+                .expect("more initial validators than MaxValidators");
+            Validators::<T>::put(validators);
+        }
+    }
+}
+
+/// Registers session keys for validators in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId> {
+    /// Make `who` a registered validator candidate.
+    fn register(who: &AccountId);
+}
+
+/// Hands the validators to `pallet_session` whenever they changed.
+impl<T: Config> pallet_session::SessionManager<T::AccountId> for Pallet<T> {
+    fn new_session(new_index: SessionIndex) -> Option<Vec<T::AccountId>> {
+        if !ValidatorsChanged::<T>::take() {
+            return None;
+        }
+
+        let validators = Validators::<T>::get().into_inner();
+        Self::deposit_event(Event::ValidatorsPlanned {
+            session_index: new_index,
+            count: validators.len() as u32,
+        });
+        Some(validators)
+    }
+
+    fn end_session(_end_index: SessionIndex) {}
+
+    fn start_session(_start_index: SessionIndex) {}
+}
+
+/// Hands the validators to `pallet_session::historical`, which needs no identification beyond
+/// the validator's account.
+impl<T: Config> pallet_session::historical::SessionManager<T::AccountId, ()> for Pallet<T> {
+    fn new_session(new_index: SessionIndex) -> Option<Vec<(T::AccountId, ())>> {
+        <Self as pallet_session::SessionManager<_>>::new_session(new_index)
+            .map(|validators| validators.into_iter().map(|validator| (validator, ())).collect())
+    }
+
+    fn end_session(_end_index: SessionIndex) {}
+
+    fn start_session(_start_index: SessionIndex) {}
+}
+
+/// Records reported offenders. Nothing is slashed; governance decides whether to remove them.
+impl<T: Config, FullIdentification>
+    OnOffenceHandler<T::AccountId, (T::AccountId, FullIdentification), Weight> for Pallet<T>
+{
+    fn on_offence(
+        offenders: &[OffenceDetails<T::AccountId, (T::AccountId, FullIdentification)>],
+        _slash_fraction: &[Perbill],
+        session_index: SessionIndex,
+    ) -> Weight {
+        for details in offenders {
+            let who = details.offender.0.clone();
+            Offenders::<T>::insert(&who, session_index);
+            Self::deposit_event(Event::OffenceReported { who, session_index });
+        }
+
+        T::DbWeight::get().writes(offenders.len() as u64)
+    }
+}
+
+/// The validators with a recorded offence.
+pub struct ReportedOffenders<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> Contains<T::AccountId> for ReportedOffenders<T> {
+    fn contains(who: &T::AccountId) -> bool {
+        Offenders::<T>::contains_key(who)
+    }
+}
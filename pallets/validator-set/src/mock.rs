@@ -0,0 +1,81 @@
+//! Mock runtime for testing the validator set pallet.
+
+use crate as pallet_validator_set;
+use frame_support::{derive_impl, parameter_types, traits::ValidatorRegistration};
+use frame_system::EnsureRoot;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+#[frame_support::runtime]
+mod test_runtime {
+    #[runtime::runtime]
+    #[runtime::derive(
+        RuntimeCall,
+        RuntimeEvent,
+        RuntimeError,
+        RuntimeOrigin,
+        RuntimeFreezeReason,
+        RuntimeHoldReason,
+        RuntimeSlashReason,
+        RuntimeLockId,
+        RuntimeTask,
+        RuntimeViewFunction
+    )]
+    pub struct Test;
+
+    #[runtime::pallet_index(0)]
+    pub type System = frame_system;
+
+    #[runtime::pallet_index(1)]
+    pub type ValidatorSet = pallet_validator_set;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+parameter_types! {
+    pub const MinValidators: u32 = 2;
+    pub const MaxValidators: u32 = 4;
+    pub static Registered: Vec<u64> = vec![1, 2, 3, 4, 5];
+}
+
+/// Accounts in `Registered` have session keys.
+pub struct TestRegistration;
+impl ValidatorRegistration<u64> for TestRegistration {
+    fn is_registered(id: &u64) -> bool {
+        Registered::get().contains(id)
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::BenchmarkHelper<u64> for TestRegistration {
+    fn register(who: &u64) {
+        let mut registered = Registered::get();
+        registered.push(*who);
+        Registered::set(registered);
+    }
+}
+
+impl pallet_validator_set::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type AddRemoveOrigin = EnsureRoot<u64>;
+    type ValidatorRegistration = TestRegistration;
+    type MinValidators = MinValidators;
+    type MaxValidators = MaxValidators;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TestRegistration;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+    pallet_validator_set::GenesisConfig::<Test> { initial_validators: vec![1, 2, 3] }
+        .assimilate_storage(&mut t)
+        .unwrap();
+    t.into()
+}
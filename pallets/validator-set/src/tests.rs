@@ -0,0 +1,97 @@
+//! Tests for the validator set pallet.
+
+use crate::{mock::*, Error, Event, ReportedOffenders};
+use frame_support::{assert_noop, assert_ok, traits::Contains};
+use pallet_session::SessionManager;
+use sp_runtime::{DispatchError, Perbill};
+use sp_staking::offence::{OffenceDetails, OnOffenceHandler};
+
+#[test]
+fn genesis_validators_are_set() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(ValidatorSet::validators().into_inner(), vec![1, 2, 3]);
+        assert!(!ValidatorSet::validators_changed());
+        
+        // The session pallet keeps the genesis validators
+        assert_eq!(ValidatorSet::new_session(1), None);
+    });
+}
+
+#[test]
+fn add_validator_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_noop!(
+            ValidatorSet::add_validator(RuntimeOrigin::signed(1), 4),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+        System::assert_last_event(Event::ValidatorAdded { who: 4 }.into());
+        assert_eq!(ValidatorSet::validators().into_inner(), vec![1, 2, 3, 4]);
+        
+        // The change is handed to the session pallet once
+        assert_eq!(ValidatorSet::new_session(1), Some(vec![1, 2, 3, 4]));
+        System::assert_last_event(Event::ValidatorsPlanned { session_index: 1, count: 4 }.into());
+        assert_eq!(ValidatorSet::new_session(2), None);
+    });
+}
+
+#[test]
+fn add_validator_checks_candidate() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ValidatorSet::add_validator(RuntimeOrigin::root(), 3),
+            Error::<Test>::AlreadyValidator
+        );
+        assert_noop!(
+            ValidatorSet::add_validator(RuntimeOrigin::root(), 6),
+            Error::<Test>::NoSessionKeys
+        );
+        
+        assert_ok!(ValidatorSet::add_validator(RuntimeOrigin::root(), 4));
+        assert_noop!(
+            ValidatorSet::add_validator(RuntimeOrigin::root(), 5),
+            Error::<Test>::TooManyValidators
+        );
+    });
+}
+
+#[test]
+fn remove_validator_keeps_minimum() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        assert_noop!(
+            ValidatorSet::remove_validator(RuntimeOrigin::root(), 4),
+            Error::<Test>::NotValidator
+        );
+        assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 2));
+        System::assert_last_event(Event::ValidatorRemoved { who: 2 }.into());
+        assert_noop!(
+            ValidatorSet::remove_validator(RuntimeOrigin::root(), 1),
+            Error::<Test>::TooFewValidators
+        );
+        
+        assert_eq!(ValidatorSet::new_session(1), Some(vec![1, 3]));
+    });
+}
+
+#[test]
+fn offences_are_recorded_until_removal() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        
+        let offence = OffenceDetails { offender: (2, ()), reporters: vec![5] };
+        ValidatorSet::on_offence(&[offence], &[Perbill::from_percent(10)], 3);
+        System::assert_last_event(Event::OffenceReported { who: 2, session_index: 3 }.into());
+        assert_eq!(ValidatorSet::offenders(2), Some(3));
+        assert!(ReportedOffenders::<Test>::contains(&2));
+        assert!(!ReportedOffenders::<Test>::contains(&1));
+        
+        // Nothing changes until the offender is removed
+        assert_eq!(ValidatorSet::validators().into_inner(), vec![1, 2, 3]);
+        assert_ok!(ValidatorSet::remove_validator(RuntimeOrigin::root(), 2));
+        assert!(!ReportedOffenders::<Test>::contains(&2));
+    });
+}
@@ -0,0 +1,101 @@
+//! Weights for pallet_validator_set
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2024-01-15, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `substrate-benchmark`, CPU: `Intel(R) Core(TM) i7-8700K CPU @ 3.70GHz`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/production/substrate-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_validator_set
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --output=./pallets/validator-set/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_validator_set.
+pub trait WeightInfo {
+    fn add_validator() -> Weight;
+    fn remove_validator() -> Weight;
+}
+
+/// Weights for pallet_validator_set using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: Session NextKeys (r:1 w:0)
+    /// Proof: Session NextKeys (max_values: None, max_size: None, mode: Measured)
+    /// Storage: ValidatorSet Validators (r:1 w:1)
+    /// Proof: ValidatorSet Validators (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+    /// Storage: ValidatorSet ValidatorsChanged (r:0 w:1)
+    /// Proof: ValidatorSet ValidatorsChanged (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+    fn add_validator() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1208`
+        //  Estimated: `4673`
+        // Minimum execution time: 17_000_000 picoseconds.
+        Weight::from_parts(18_000_000, 4673)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    
+    /// Storage: ValidatorSet Validators (r:1 w:1)
+    /// Proof: ValidatorSet Validators (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+    /// Storage: ValidatorSet ValidatorsChanged (r:0 w:1)
+    /// Proof: ValidatorSet ValidatorsChanged (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+    fn remove_validator() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1059`
+        //  Estimated: `2510`
+        // Minimum execution time: 12_000_000 picoseconds.
+        Weight::from_parts(13_000_000, 2510)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    /// Storage: Session NextKeys (r:1 w:0)
+    /// Proof: Session NextKeys (max_values: None, max_size: None, mode: Measured)
+    /// Storage: ValidatorSet Validators (r:1 w:1)
+    /// Proof: ValidatorSet Validators (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+    /// Storage: ValidatorSet ValidatorsChanged (r:0 w:1)
+    /// Proof: ValidatorSet ValidatorsChanged (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+    fn add_validator() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1208`
+        //  Estimated: `4673`
+        // Minimum execution time: 17_000_000 picoseconds.
+        Weight::from_parts(18_000_000, 4673)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    
+    /// Storage: ValidatorSet Validators (r:1 w:1)
+    /// Proof: ValidatorSet Validators (max_values: Some(1), max_size: Some(1025), added: 1520, mode: MaxEncodedLen)
+    /// Storage: ValidatorSet ValidatorsChanged (r:0 w:1)
+    /// Proof: ValidatorSet ValidatorsChanged (max_values: Some(1), max_size: Some(1), added: 496, mode: MaxEncodedLen)
+    fn remove_validator() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `1059`
+        //  Estimated: `2510`
+        // Minimum execution time: 12_000_000 picoseconds.
+        Weight::from_parts(13_000_000, 2510)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+}
@@ -25,8 +25,10 @@
 
 // External crates imports
 use alloc::vec::Vec;
+use codec::Encode;
 use frame_support::{
 	genesis_builder_helper::{build_state, get_preset},
+	traits::KeyOwnerProofSystem,
 	weights::Weight,
 };
 use pallet_grandpa::AuthorityId as GrandpaId;
@@ -42,8 +44,9 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
-	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, Hash, Historical,
+	InherentDataExt, Nonce, Runtime, RuntimeCall, RuntimeEvent, RuntimeGenesisConfig, SessionKeys,
+	SimpleGovernance, System, Template, TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -150,23 +153,24 @@ impl_runtime_apis! {
 		}
 
 		fn submit_report_equivocation_unsigned_extrinsic(
-			_equivocation_proof: sp_consensus_grandpa::EquivocationProof<
+			equivocation_proof: sp_consensus_grandpa::EquivocationProof<
 				<Block as BlockT>::Hash,
 				NumberFor<Block>,
 			>,
-			_key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
+			key_owner_proof: sp_consensus_grandpa::OpaqueKeyOwnershipProof,
 		) -> Option<()> {
-			None
+			let key_owner_proof = key_owner_proof.decode()?;
+
+			Grandpa::submit_unsigned_equivocation_report(equivocation_proof, key_owner_proof)
 		}
 
 		fn generate_key_ownership_proof(
 			_set_id: sp_consensus_grandpa::SetId,
-			_authority_id: GrandpaId,
+			authority_id: GrandpaId,
 		) -> Option<sp_consensus_grandpa::OpaqueKeyOwnershipProof> {
-			// NOTE: this is the only implementation possible since we've
-			// defined our key owner proof type as a bottom type (i.e. a type
-			// with no values).
-			None
+			Historical::prove((sp_consensus_grandpa::KEY_TYPE, authority_id))
+				.map(|proof| proof.encode())
+				.map(sp_consensus_grandpa::OpaqueKeyOwnershipProof::new)
 		}
 	}
 
@@ -220,6 +224,60 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_template::runtime_api::ConfigRegistryApi<
+		Block,
+		pallet_simple_governance::ProposalId,
+		BlockNumber,
+	> for Runtime {
+		fn value(key: Vec<u8>) -> Option<pallet_template::ConfigValue> {
+			Template::value(&key)
+		}
+
+		fn entries() -> Vec<(Vec<u8>, pallet_template::ConfigValue)> {
+			Template::entries()
+		}
+
+		fn history(
+			key: Vec<u8>,
+		) -> Vec<pallet_template::ConfigChange<pallet_simple_governance::ProposalId, BlockNumber>> {
+			Template::history(&key)
+		}
+	}
+
+	impl pallet_simple_governance::runtime_api::GovernanceApi<Block, RuntimeEvent, Hash> for Runtime {
+		fn dry_run(
+			proposal_id: pallet_simple_governance::ProposalId,
+		) -> Result<
+			pallet_simple_governance::DryRunEffects<RuntimeEvent, Hash>,
+			pallet_simple_governance::DryRunError,
+		> {
+			SimpleGovernance::dry_run(proposal_id)
+		}
+	}
+
+	impl pallet_simple_governance::runtime_api::GovernanceVotesApi<
+		Block,
+		AccountId,
+		Balance,
+		BlockNumber,
+		Hash,
+	> for Runtime {
+		fn votes_of(
+			who: AccountId,
+			start_after: Option<pallet_simple_governance::ProposalId>,
+			limit: u32,
+		) -> Vec<(
+			pallet_simple_governance::ProposalId,
+			pallet_simple_governance::VoteRecord<Balance, BlockNumber, Hash>,
+		)> {
+			SimpleGovernance::votes_of(&who, start_after, limit)
+		}
+
+		fn participation(who: AccountId) -> pallet_simple_governance::ParticipationStats {
+			SimpleGovernance::participation(&who)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
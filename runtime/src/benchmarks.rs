@@ -32,4 +32,9 @@ frame_benchmarking::define_benchmarks!(
 	[pallet_sudo, Sudo]
 	[pallet_template, Template]
 	[pallet_simple_governance, SimpleGovernance]
+	[pallet_treasury, Treasury]
+	[pallet_vesting, Vesting]
+	[pallet_collective, Council]
+	[pallet_membership, CouncilMembership]
+	[pallet_validator_set, ValidatorSet]
 );
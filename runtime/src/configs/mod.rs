@@ -24,25 +24,47 @@
 // For more information, please refer to <http://unlicense.org>
 
 // Substrate and Polkadot dependencies
+use codec::Encode;
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
+	traits::{
+		tokens::{imbalance::ResolveTo, pay::PayFromAccount, UnityAssetBalanceConversion},
+		ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, EitherOf, EitherOfDiverse,
+		VariantCountOf, WithdrawReasons,
+	},
+	PalletId,
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
 	},
 };
-use frame_system::limits::{BlockLength, BlockWeights};
+use frame_system::{
+	limits::{BlockLength, BlockWeights},
+	EnsureNever, EnsureRoot, EnsureRootWithSuccess, EnsureSigned, EnsureWithSuccess,
+};
+use pallet_collective::EnsureProportionAtLeast;
+use pallet_simple_governance::{Curve, TrackId, TrackInfo, TracksInfo};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+	generic,
+	traits::{
+		AccountIdConversion, Convert, ConvertInto, Dispatchable, IdentityLookup, One, OpaqueKeys,
+		SaturatedConversion, StaticLookup, Verify,
+	},
+	transaction_validity::{TransactionLongevity, TransactionPriority},
+	DispatchResult, Perbill, Permill,
+};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+	AccountId, Aura, Balance, Balances, Block, BlockNumber, Council, Hash, Historical, Nonce,
+	Offences, PalletInfo, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
+	RuntimeHoldReason, RuntimeOrigin, RuntimeTask, Session, SessionKeys, Signature, SignedPayload,
+	Sudo, System, Treasury, TxExtension, UncheckedExtrinsic, ValidatorSet, Vesting, DAYS,
+	EXISTENTIAL_DEPOSIT, HOURS,
+	SLOT_DURATION, UNIT, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -98,16 +120,90 @@ impl pallet_aura::Config for Runtime {
 	type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Runtime>;
 }
 
+parameter_types! {
+	pub const SessionPeriod: BlockNumber = HOURS;
+	pub const SessionOffset: BlockNumber = 0;
+	pub const MinValidators: u32 = 1;
+	pub const MaxValidators: u32 = 32;
+	/// Sessions for which GRANDPA keeps set IDs, so equivocations can be reported for a week.
+	pub const MaxSetIdSessionEntries: u64 = 7 * 24;
+	pub ReportLongevity: u64 = MaxSetIdSessionEntries::get() * SessionPeriod::get() as u64;
+}
+
+impl pallet_session::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = ConvertInto;
+	type ShouldEndSession = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type NextSessionRotation = pallet_session::PeriodicSessions<SessionPeriod, SessionOffset>;
+	type SessionManager = pallet_session::historical::NoteHistoricalRoot<Runtime, ValidatorSet>;
+	type SessionHandler = <SessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+	type Keys = SessionKeys;
+	type DisablingStrategy = ();
+	type WeightInfo = pallet_session::weights::SubstrateWeight<Runtime>;
+}
+
+/// Validators are identified by their account alone.
+pub struct ValidatorIdentification;
+impl Convert<AccountId, Option<()>> for ValidatorIdentification {
+	fn convert(_validator: AccountId) -> Option<()> {
+		Some(())
+	}
+}
+
+impl pallet_session::historical::Config for Runtime {
+	type FullIdentification = ();
+	type FullIdentificationOf = ValidatorIdentification;
+}
+
+/// Offenders are recorded by the validator set for governance to judge, not slashed.
+impl pallet_offences::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
+	type OnOffenceHandler = ValidatorSet;
+}
+
+/// Validators are added and removed by approved governance proposals, which act as root.
+impl pallet_validator_set::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_validator_set::weights::SubstrateWeight<Runtime>;
+	type AddRemoveOrigin = EnsureRoot<AccountId>;
+	type ValidatorRegistration = Session;
+	type MinValidators = MinValidators;
+	type MaxValidators = MaxValidators;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ValidatorSetBenchmarkHelper;
+}
+
+/// Funds benchmarked validators and registers empty session keys for them.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ValidatorSetBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_validator_set::BenchmarkHelper<AccountId> for ValidatorSetBenchmarkHelper {
+	fn register(who: &AccountId) {
+		use codec::Decode;
+		use frame_support::traits::Currency;
+		use sp_runtime::traits::TrailingZeroInput;
+
+		Balances::make_free_balance_be(who, EXISTENTIAL_DEPOSIT * 1_000);
+		let keys = SessionKeys::decode(&mut TrailingZeroInput::zeroes())
+			.expect("session keys decode from zeroes");
+		Session::set_keys(RuntimeOrigin::signed(who.clone()), keys, alloc::vec![])
+			.expect("session keys can be set");
+	}
+}
+
 impl pallet_grandpa::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 
 	type WeightInfo = ();
 	type MaxAuthorities = ConstU32<32>;
 	type MaxNominators = ConstU32<0>;
-	type MaxSetIdSessionEntries = ConstU64<0>;
+	type MaxSetIdSessionEntries = MaxSetIdSessionEntries;
 
-	type KeyOwnerProof = sp_core::Void;
-	type EquivocationReportSystem = ();
+	type KeyOwnerProof = sp_session::MembershipProof;
+	type EquivocationReportSystem =
+		pallet_grandpa::EquivocationReportSystem<Self, Offences, Historical, ReportLongevity>;
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -126,7 +222,7 @@ impl pallet_balances::Config for Runtime {
 	type Balance = Balance;
 	/// The ubiquitous event type.
 	type RuntimeEvent = RuntimeEvent;
-	type DustRemoval = ();
+	type DustRemoval = ResolveTo<TreasuryAccount, Balances>;
 	type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
 	type AccountStore = System;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
@@ -143,7 +239,7 @@ parameter_types! {
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = FungibleAdapter<Balances, ()>;
+	type OnChargeTransaction = FungibleAdapter<Balances, ResolveTo<TreasuryAccount, Balances>>;
 	type OperationalFeeMultiplier = ConstU8<5>;
 	type WeightToFee = IdentityFee<Balance>;
 	type LengthToFee = IdentityFee<Balance>;
@@ -151,6 +247,11 @@ impl pallet_transaction_payment::Config for Runtime {
 	type WeightInfo = pallet_transaction_payment::weights::SubstrateWeight<Runtime>;
 }
 
+/// Lets calls marked `feeless_if`, such as first governance votes, skip the transaction fee.
+impl pallet_skip_feeless_payment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
+
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -161,12 +262,324 @@ impl pallet_sudo::Config for Runtime {
 impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+	type ProposalId = pallet_simple_governance::ProposalId;
+	/// Only proposals passed on the technical governance track may change the registry.
+	type UpdateOrigin = pallet_simple_governance::EnsureTrack<1>;
+	type MaxKeyLength = ConstU32<64>;
+	type MaxHistory = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub TreasuryAccount: AccountId = Treasury::account_id();
+	pub const SpendPeriod: BlockNumber = DAYS;
+	pub const Burn: Permill = Permill::zero();
+	pub const PayoutSpendPeriod: BlockNumber = 30 * DAYS;
+	pub const MaxApprovals: u32 = 100;
+	pub const MaxBalance: Balance = Balance::MAX;
+}
+
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type RejectOrigin = EnsureRoot<AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type MaxApprovals = MaxApprovals;
+	/// Governance spends are paid by `pallet_simple_governance` within its per-track limits,
+	/// so only root can spend through the treasury directly.
+	type SpendOrigin = EnsureRootWithSuccess<AccountId, MaxBalance>;
+	type AssetKind = ();
+	type Beneficiary = AccountId;
+	type BeneficiaryLookup = IdentityLookup<Self::Beneficiary>;
+	type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
+	type BalanceConverter = UnityAssetBalanceConversion;
+	type PayoutPeriod = PayoutSpendPeriod;
+	type BlockNumberProvider = System;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+parameter_types! {
+	pub const MinVestedTransfer: Balance = UNIT;
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = MinVestedTransfer;
+	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type BlockNumberProvider = System;
+	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
 parameter_types! {
+	pub const CouncilMotionDuration: BlockNumber = DAYS;
+	pub const CouncilMaxProposals: u32 = 100;
+	pub const CouncilMaxMembers: u32 = 13;
+	pub MaxCouncilProposalWeight: Weight =
+		Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
+	pub const CouncilPalletId: PalletId = PalletId(*b"py/cncil");
+	/// The account proposals created by the council are attributed to.
+	pub CouncilAccount: AccountId = CouncilPalletId::get().into_account_truncating();
+}
+
+type CouncilCollective = pallet_collective::Instance1;
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = CouncilMotionDuration;
+	type MaxProposals = CouncilMaxProposals;
+	type MaxMembers = CouncilMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+	/// Members are managed by `CouncilMembership` only.
+	type SetMembersOrigin = EnsureNever<()>;
+	type MaxProposalWeight = MaxCouncilProposalWeight;
+	type DisapproveOrigin = EnsureRoot<AccountId>;
+	type KillOrigin = EnsureRoot<AccountId>;
+	type Consideration = ();
+}
+
+/// Council members are added and removed by approved governance proposals, which act as root.
+type CouncilMembershipInstance = pallet_membership::Instance1;
+impl pallet_membership::Config<CouncilMembershipInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = EnsureRoot<AccountId>;
+	type RemoveOrigin = EnsureRoot<AccountId>;
+	type SwapOrigin = EnsureRoot<AccountId>;
+	type ResetOrigin = EnsureRoot<AccountId>;
+	type PrimeOrigin = EnsureRoot<AccountId>;
+	type MembershipInitialized = Council;
+	type MembershipChanged = Council;
+	type MaxMembers = CouncilMaxMembers;
+	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type RuntimeCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateBare<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_bare(call: RuntimeCall) -> UncheckedExtrinsic {
+		UncheckedExtrinsic::new_bare(call)
+	}
+}
+
+/// Lets off-chain workers sign transactions with the same extensions as regular ones.
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_signed_transaction<
+		C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>,
+	>(
+		call: RuntimeCall,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Nonce,
+	) -> Option<UncheckedExtrinsic> {
+		let period =
+			BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let tx_ext: TxExtension = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0).into(),
+			frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+			frame_system::WeightReclaim::<Runtime>::new(),
+		);
+
+		let raw_payload = SignedPayload::new(call, tx_ext).ok()?;
+		let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+		let (call, tx_ext, _) = raw_payload.deconstruct();
+		Some(UncheckedExtrinsic::new_signed(call, account.into(), signature, tx_ext))
+	}
+}
+
+parameter_types! {
+    // Initial values of the parameters the council can change with `set_parameters`
     pub const MaxDescriptionLength: u32 = 256;
     pub const DefaultVotingPeriod: BlockNumber = 7 * crate::DAYS; // 7 days in blocks
     pub const MaxProposalsPerBlock: u32 = 10;
+    // Bounds of the voting period `set_parameters` can set
+    pub const MinVotingPeriod: BlockNumber = crate::DAYS;
+    pub const MaxVotingPeriod: BlockNumber = 28 * crate::DAYS;
+    // Calls dispatched by approved proposals may use up to a tenth of a block
+    pub MaxCallWeight: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
+    pub const MaxActiveProposalsPerAccount: u32 = 3;
+    pub const MaxActiveProposals: u32 = 100;
+    pub const ProposalCooldown: BlockNumber = 10 * crate::MINUTES;
+    // Expected number of voters, used to measure support. Any account may vote, so the
+    // electorate is only an estimate: proposals are neither decided nor approved before
+    // their end block.
+    pub const EligibleElectorate: u32 = 100;
+    pub const EarlyDecisionThreshold: Option<Perbill> = None;
+    pub const EarlyApproval: bool = false;
+    pub const ConfirmationWindow: BlockNumber = crate::HOURS;
+    pub const DeadlineExtension: BlockNumber = crate::HOURS;
+    pub const MaxDeadlineExtensions: u32 = 3;
+    pub const MaxVotesRemovedPerTask: u32 = 500;
+    pub const MaxFreeVotesPerBlock: u32 = 100;
+    pub const MaxBatchSize: u32 = 16;
+    pub const GovernanceUnsignedPriority: TransactionPriority = TransactionPriority::MAX / 2;
+    pub const GovernanceUnsignedLongevity: TransactionLongevity = 16;
+    pub const MinFastTrackPeriod: BlockNumber = crate::HOURS;
+}
+
+/// Maximum amount a single spend proposal may request on each governance track.
+pub struct GovernanceSpendLimit;
+impl Convert<TrackId, Balance> for GovernanceSpendLimit {
+    fn convert(track: TrackId) -> Balance {
+        match track {
+            0 => 1_000 * UNIT,
+            1 => 100 * UNIT,
+            _ => 0,
+        }
+    }
+}
+
+/// The sudo key, which governance removes by calling `remove_key` as the key itself.
+pub struct GovernanceSudoKey;
+impl pallet_simple_governance::SudoKey for GovernanceSudoKey {
+    fn exists() -> bool {
+        pallet_sudo::Key::<Runtime>::get().is_some()
+    }
+
+    fn remove() -> DispatchResult {
+        let key =
+            pallet_sudo::Key::<Runtime>::get().ok_or(pallet_sudo::Error::<Runtime>::RequireSudo)?;
+        Sudo::remove_key(RuntimeOrigin::signed(key)).map(|_| ()).map_err(|e| e.error)
+    }
+}
+
+/// The council, whose members approved governance proposals change through `CouncilMembership`.
+pub struct GovernanceCommittee;
+impl pallet_simple_governance::ManageMembers<AccountId> for GovernanceCommittee {
+    fn add_member(who: &AccountId) -> DispatchResult {
+        let who = <Runtime as frame_system::Config>::Lookup::unlookup(who.clone());
+        RuntimeCall::CouncilMembership(pallet_membership::Call::add_member { who })
+            .dispatch(RuntimeOrigin::root())
+            .map(|_| ())
+            .map_err(|e| e.error)
+    }
+
+    fn remove_member(who: &AccountId) -> DispatchResult {
+        let who = <Runtime as frame_system::Config>::Lookup::unlookup(who.clone());
+        RuntimeCall::CouncilMembership(pallet_membership::Call::remove_member { who })
+            .dispatch(RuntimeOrigin::root())
+            .map(|_| ())
+            .map_err(|e| e.error)
+    }
+}
+
+/// The validator set, which approved governance proposals change from the next session on.
+pub struct GovernanceValidators;
+impl pallet_simple_governance::ManageMembers<AccountId> for GovernanceValidators {
+    fn add_member(who: &AccountId) -> DispatchResult {
+        ValidatorSet::do_add_validator(who.clone())
+    }
+
+    fn remove_member(who: &AccountId) -> DispatchResult {
+        ValidatorSet::do_remove_validator(who.clone())
+    }
+}
+
+/// Any account, or half of the council on behalf of `CouncilAccount`.
+pub type GovernanceProposeOrigin = EitherOf<
+    EnsureSigned<AccountId>,
+    EnsureWithSuccess<
+        EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+        AccountId,
+        CouncilAccount,
+    >,
+>;
+
+/// Half of the council on behalf of `CouncilAccount`. Proposals that spend funds, upgrade
+/// the runtime or change memberships start with the council, so cheap accounts cannot
+/// push them through.
+pub type GovernanceActionProposeOrigin = EnsureWithSuccess<
+    EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>,
+    AccountId,
+    CouncilAccount,
+>;
+
+/// Root or two thirds of the council.
+pub type CouncilTwoThirdsOrRoot = EitherOfDiverse<
+    EnsureRoot<AccountId>,
+    EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>,
+>;
+
+/// Root or the whole council.
+pub type CouncilUnanimousOrRoot = EitherOfDiverse<
+    EnsureRoot<AccountId>,
+    EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>,
+>;
+
+/// Voting tracks of the governance pallet.
+pub struct GovernanceTracks;
+impl TracksInfo for GovernanceTracks {
+    fn tracks() -> &'static [(TrackId, TrackInfo)] {
+        static TRACKS: [(TrackId, TrackInfo); 2] = [
+            (
+                0,
+                TrackInfo {
+                    name: "general",
+                    min_approval: Curve::LinearDecreasing {
+                        ceil: Perbill::from_percent(100),
+                        floor: Perbill::from_percent(50),
+                    },
+                    min_support: Curve::LinearDecreasing {
+                        ceil: Perbill::from_percent(50),
+                        floor: Perbill::from_percent(10),
+                    },
+                },
+            ),
+            (
+                1,
+                TrackInfo {
+                    name: "technical",
+                    min_approval: Curve::Reciprocal {
+                        ceil: Perbill::from_percent(100),
+                        floor: Perbill::from_percent(60),
+                        factor: 9,
+                    },
+                    min_support: Curve::Reciprocal {
+                        ceil: Perbill::from_percent(30),
+                        floor: Perbill::from_percent(5),
+                        factor: 9,
+                    },
+                },
+            ),
+        ];
+        &TRACKS
+    }
 }
 
 impl pallet_simple_governance::Config for Runtime {
@@ -175,4 +588,73 @@ impl pallet_simple_governance::Config for Runtime {
     type MaxDescriptionLength = MaxDescriptionLength;
     type DefaultVotingPeriod = DefaultVotingPeriod;
     type MaxProposalsPerBlock = MaxProposalsPerBlock;
-}
\ No newline at end of file
+    type MaxActiveProposalsPerAccount = MaxActiveProposalsPerAccount;
+    type MaxActiveProposals = MaxActiveProposals;
+    type ProposalCooldown = ProposalCooldown;
+    type EligibleElectorate = EligibleElectorate;
+    type EarlyDecisionThreshold = EarlyDecisionThreshold;
+    type EarlyApproval = EarlyApproval;
+    type ConfirmationWindow = ConfirmationWindow;
+    type DeadlineExtension = DeadlineExtension;
+    type MaxDeadlineExtensions = MaxDeadlineExtensions;
+    type Tracks = GovernanceTracks;
+    type MaxVotesRemovedPerTask = MaxVotesRemovedPerTask;
+    type AuthorityId = pallet_simple_governance::offchain::crypto::GovernanceAuthId;
+    type UnsignedPriority = GovernanceUnsignedPriority;
+    type UnsignedLongevity = GovernanceUnsignedLongevity;
+    type Currency = Balances;
+    type Vesting = Vesting;
+    type TreasuryAccount = TreasuryAccount;
+    type SpendLimit = GovernanceSpendLimit;
+    type FastTrackOrigin = CouncilTwoThirdsOrRoot;
+    type InstantOrigin = CouncilUnanimousOrRoot;
+    type MinFastTrackPeriod = MinFastTrackPeriod;
+    type SudoKey = GovernanceSudoKey;
+    type ProposeOrigin = GovernanceProposeOrigin;
+    type ActionProposeOrigin = GovernanceActionProposeOrigin;
+    type CancelOrigin = CouncilTwoThirdsOrRoot;
+    type Committee = GovernanceCommittee;
+    type Validators = GovernanceValidators;
+    type Offenders = pallet_validator_set::ReportedOffenders<Runtime>;
+    type MinVotingPeriod = MinVotingPeriod;
+    type MaxVotingPeriod = MaxVotingPeriod;
+    type ParametersOrigin = CouncilTwoThirdsOrRoot;
+    type GovernanceHooks = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type ProposalCall = RuntimeCall;
+    type MaxCallWeight = MaxCallWeight;
+    type MaxFreeVotesPerBlock = MaxFreeVotesPerBlock;
+    type MaxBatchSize = MaxBatchSize;
+    type BallotSignature = Signature;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = GovernanceBenchmarkHelper;
+}
+
+/// Signs benchmarked ballots with an sr25519 key generated in the benchmark's keystore.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct GovernanceBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_simple_governance::BenchmarkHelper<AccountId, Signature>
+    for GovernanceBenchmarkHelper
+{
+    fn voter() -> AccountId {
+        use sp_runtime::{app_crypto::RuntimePublic, traits::IdentifyAccount};
+
+        let public = sp_core::sr25519::Public::generate_pair(BALLOT_KEY_TYPE, None);
+        sp_runtime::MultiSigner::from(public).into_account()
+    }
+
+    fn sign(who: &AccountId, message: &[u8]) -> Signature {
+        use sp_runtime::app_crypto::RuntimePublic;
+
+        let public = sp_core::sr25519::Public::from_raw(who.clone().into());
+        public
+            .sign(BALLOT_KEY_TYPE, &message)
+            .expect("the key was generated by `voter`; qed")
+            .into()
+    }
+}
+
+/// Key type of the keys benchmarked ballots are signed with.
+#[cfg(feature = "runtime-benchmarks")]
+const BALLOT_KEY_TYPE: sp_core::crypto::KeyTypeId = sp_core::crypto::KeyTypeId(*b"gbal");
\ No newline at end of file
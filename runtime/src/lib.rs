@@ -7,6 +7,7 @@ pub mod apis;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarks;
 pub mod configs;
+pub mod migrations;
 
 extern crate alloc;
 use alloc::vec::Vec;
@@ -67,12 +68,14 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
-	//   the compatible custom types.
-	spec_version: 100,
+	// This value started at 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
+	//   the compatible custom types. Bump it with every runtime upgrade.
+	spec_version: 101,
 	impl_version: 1,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Bump this whenever call indices or the transaction extensions change, which invalidates
+	//   transactions signed for the previous version.
+	transaction_version: 2,
 	system_version: 1,
 };
 
@@ -155,7 +158,10 @@ pub type TxExtension = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_skip_feeless_payment::SkipCheckIfFeeless<
+		Runtime,
+		pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	frame_system::WeightReclaim<Runtime>,
 );
@@ -167,11 +173,15 @@ pub type UncheckedExtrinsic =
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 
-/// All migrations of the runtime, aside from the ones declared in the pallets.
+/// All migrations of the runtime.
 ///
-/// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
+/// This can be a tuple of types, each implementing `OnRuntimeUpgrade`. Unversioned migrations
+/// run on every upgrade they are included in, so remove them once they have been applied.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_simple_governance::migrations::MigrateToLatest<Runtime>,
+	pallet_template::migrations::v1::MigrateToV1<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -207,6 +217,20 @@ mod runtime {
 	#[runtime::pallet_index(1)]
 	pub type Timestamp = pallet_timestamp;
 
+	// The validator set and session must come before Aura and Grandpa, so session changes are
+	// applied before the authorities are used in a block.
+	#[runtime::pallet_index(13)]
+	pub type ValidatorSet = pallet_validator_set;
+
+	#[runtime::pallet_index(14)]
+	pub type Session = pallet_session;
+
+	#[runtime::pallet_index(15)]
+	pub type Historical = pallet_session::historical;
+
+	#[runtime::pallet_index(16)]
+	pub type Offences = pallet_offences;
+
 	#[runtime::pallet_index(2)]
 	pub type Aura = pallet_aura;
 
@@ -228,4 +252,19 @@ mod runtime {
 
 	#[runtime::pallet_index(8)] // Choose an available index
     pub type SimpleGovernance = pallet_simple_governance;
+
+	#[runtime::pallet_index(9)]
+	pub type Treasury = pallet_treasury;
+
+	#[runtime::pallet_index(10)]
+	pub type Vesting = pallet_vesting;
+
+	#[runtime::pallet_index(11)]
+	pub type Council = pallet_collective<Instance1>;
+
+	#[runtime::pallet_index(12)]
+	pub type CouncilMembership = pallet_membership<Instance1>;
+
+	#[runtime::pallet_index(17)]
+	pub type SkipFeelessPayment = pallet_skip_feeless_payment;
 }
@@ -0,0 +1,15 @@
+//! Runtime migrations that are not part of a pallet.
+
+use frame_support::{
+	migrations::RemovePallet, parameter_types, weights::constants::RocksDbWeight,
+};
+
+parameter_types! {
+	pub const SudoPalletName: &'static str = "Sudo";
+}
+
+/// Removes all storage of the `Sudo` pallet.
+///
+/// Only add this to `Migrations` in the runtime upgrade that drops `Sudo` from the runtime,
+/// after governance has removed the sudo key, and remove it again in the next one.
+pub type RemoveSudoStorage = RemovePallet<SudoPalletName, RocksDbWeight>;